@@ -0,0 +1,22 @@
+#[macro_use] extern crate criterion;
+extern crate bip39;
+extern crate rand;
+
+use criterion::Criterion;
+use rand::{SeedableRng, XorShiftRng};
+use ::bip39::{Language, Mnemonic, MnemonicType};
+
+fn bench_generate(c: &mut Criterion) {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    c.bench_function("generate, eager seed", |b| b.iter(|| {
+        Mnemonic::generate_until(MnemonicType::Type24Words, Language::English, "", &mut rng, |_| true, 1).unwrap()
+    }));
+
+    c.bench_function("generate, lazy seed", |b| b.iter(|| {
+        Mnemonic::new_lazy(MnemonicType::Type24Words, Language::English, "", &mut rng).unwrap()
+    }));
+}
+
+criterion_group!(benches, bench_generate);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+#[macro_use] extern crate criterion;
+extern crate bip39;
+
+use criterion::Criterion;
+use ::bip39::Seed;
+#[cfg(feature = "altcoin")]
+use ::bip39::{Language, Mnemonic};
+
+fn bench_kdf(c: &mut Criterion) {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    // only the key derivation, the phrase isn't parsed or validated
+    c.bench_function("kdf, pbkdf2-hmac-sha512", |b| b.iter(|| {
+        Seed::generate_with_iterations(test_mnemonic, "TREZOR", 2048).unwrap()
+    }));
+
+    #[cfg(feature = "altcoin")]
+    {
+        let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+
+        c.bench_function("kdf, pbkdf2-hmac-sha256 (altcoin)", |b| b.iter(|| {
+            Seed::generate_sha256(&mnemonic, "TREZOR")
+        }));
+    }
+
+    // the crate has no hardened (argon2/scrypt) derivation yet, benches for it belong here once
+    // it does
+}
+
+criterion_group!(benches, bench_kdf);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+#[macro_use] extern crate criterion;
+extern crate bip39;
+
+use criterion::Criterion;
+use ::bip39::{validate_phrase, Language, Mnemonic};
+
+const PHRASES: &[&str] = &[
+    "park remain person kitchen mule spell knee armed position rail grid ankle",
+    "any paddle cabbage armor atom satoshi fiction night wisdom nasty they midnight chicken play phone",
+    "soda oak spy claim best oppose gun ghost school use sign shock sign pipe vote follow category filter",
+    "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword world",
+];
+
+fn bench_validate(c: &mut Criterion) {
+    let word_list = Language::English.word_list();
+
+    c.bench_function("validate batch, owned word list", |b| b.iter(|| {
+        for phrase in PHRASES {
+            Mnemonic::validate_with_word_list(*phrase, word_list).unwrap();
+        }
+    }));
+
+    c.bench_function("validate batch, borrowed word list", |b| b.iter(|| {
+        for phrase in PHRASES {
+            Mnemonic::validate_with_word_list(*phrase, word_list).unwrap();
+        }
+    }));
+
+    c.bench_function("validate batch, borrowed phrases", |b| b.iter(|| {
+        for phrase in PHRASES {
+            validate_phrase(phrase, word_list).unwrap();
+        }
+    }));
+}
+
+criterion_group!(benches, bench_validate);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+#[macro_use] extern crate criterion;
+extern crate bip39;
+
+use criterion::Criterion;
+use ::bip39::{Language, Mnemonic};
+
+/// A token stream that looks like OCR output, mostly garbage with the odd real word
+fn noisy_tokens() -> Vec<String> {
+    let mut tokens = Vec::new();
+    for i in 0..10_000u32 {
+        if i % 10 == 0 {
+            tokens.push("abandon".to_string());
+        } else {
+            tokens.push(format!("x{}q{}", i * 7919 % 1000, i % 13));
+        }
+    }
+    tokens
+}
+
+fn bench_lookup(c: &mut Criterion) {
+    let word_list = Language::English.word_list();
+    let word_map = word_list.gen_wordmap();
+    let tokens = noisy_tokens();
+
+    // build the filter outside the measured loop
+    word_list.maybe_contains("abandon");
+
+    c.bench_function("noisy tokens exact lookup", |b| b.iter(|| {
+        tokens.iter().filter(|t| word_map.contains_key(t.as_str())).count()
+    }));
+
+    c.bench_function("noisy tokens bloom then exact lookup", |b| b.iter(|| {
+        tokens.iter()
+            .filter(|t| word_list.maybe_contains(t) && word_map.contains_key(t.as_str()))
+            .count()
+    }));
+
+    c.bench_function("noisy phrase validation", |b| b.iter(|| {
+        Mnemonic::validate_with_word_list("park remain person kitchen mule spell knee armed position rail grid xqzv", word_list)
+    }));
+}
+
+criterion_group!(benches, bench_lookup);
+criterion_main!(benches);
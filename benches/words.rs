@@ -0,0 +1,28 @@
+#[macro_use] extern crate criterion;
+extern crate bip39;
+
+use criterion::{black_box, Criterion};
+use ::bip39::{Language, Mnemonic};
+
+fn bench_words(c: &mut Criterion) {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword world";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+
+    // one "frame" reads every word once
+    c.bench_function("words, split each time", |b| b.iter(|| {
+        for i in 0..24 {
+            black_box(mnemonic.as_str().split(" ").nth(i));
+        }
+    }));
+
+    c.bench_function("words, cached slice", |b| b.iter(|| {
+        for i in 0..24 {
+            black_box(&mnemonic.words_as_slice()[i]);
+        }
+    }));
+}
+
+criterion_group!(benches, bench_words);
+criterion_main!(benches);
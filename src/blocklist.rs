@@ -0,0 +1,43 @@
+//! Entropy of well known, publicly published mnemonic phrases.
+//!
+//! Anything in here is effectively a public key to a wallet, so funds sent to it can be taken by
+//! anyone. The list is matched on entropy so it catches these phrases in every language.
+//!
+
+/// Lowercase hex entropy of each known phrase
+pub(crate) static KNOWN_TEST_ENTROPY: &[&str] = &[
+    // official BIP39 test vectors
+    "00000000000000000000000000000000",
+    "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+    "80808080808080808080808080808080",
+    "ffffffffffffffffffffffffffffffff",
+    "000000000000000000000000000000000000000000000000",
+    "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+    "808080808080808080808080808080808080808080808080",
+    "ffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+    "8080808080808080808080808080808080808080808080808080808080808080",
+    "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "9e885d952ad362caeb4efe34a8e91bd2",
+    "6610b25967cdcca9d59875f5cb50b0ea75433311869e930b",
+    "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+    "c0ba5a8e914111210f2bd131f3d5e08d",
+    "6d9be1ee6ebd27a258115aad99b7317b9c8d28b6d76431c3",
+    "9f6a2878b2520799a44ef18bc7df394e7061a224d2c33cd015b157d746869863",
+    "23db8160a31d3e97dca3688e256b9d4c",
+    "8197a4a47f0425faeaa69deebc05ca29c0a5b5cc76ceacc0",
+    "066dca1a2bb7e8a1db2832148ce9933eea0f3ac9548d793112d9a95c9407efad",
+    "f30f8c1da665478f49b001d94c5fc452",
+    "c10ec20dc3cd9f652c7fac2f1230f7a3c828389a14392f05",
+    "f585c11aec520db57dd353c69554b21a89b20fb0650966fa0a9d6f74fd989d8f",
+    // "test test test test test test test test test test test junk", the Hardhat/Foundry default
+    "df9bf37e6fcdf9bf37e6fcdf9bf37e3c",
+    // examples from this crate's own documentation and tests
+    "33e46bb13a746ea41cdde45c90846a79",
+    "a056b28d3d8915a25ee05ea8761d9984",
+    "0a33d87e85f0e57ed574acfc52678246327b4ca8",
+    "ce32fb4d14d1553719fb0cc0ddf721632c854abd7ad5242a",
+    "af3e02724cba78dc01f7179b057320fd80a0c6e20db952cf9d2ea969",
+    "078ceee01e6f734e188f07ab565a1e1be624a98a8e437fc35803899e39c4f717",
+];
@@ -0,0 +1,74 @@
+//! A small fixed-size bloom filter used to reject words that are definitely not part of a
+//! [`WordList`][WordList] before doing an exact lookup.
+//!
+//! [WordList]: ../mnemonic/struct.WordList.html
+//!
+
+use std::prelude::v1::*;
+
+/// Number of bits in the filter, 16 bits per word for a standard 2048 word list
+const BLOOM_BITS: usize = 32768;
+
+/// Number of bit positions probed for each word
+const BLOOM_HASHES: u64 = 6;
+
+#[derive(Debug, Clone)]
+pub(crate) struct BloomFilter {
+    bits: Vec<u64>,
+}
+
+impl BloomFilter {
+
+    /// Build a filter containing every word in `words`
+    pub(crate) fn new<'a, I>(words: I) -> BloomFilter where I: IntoIterator<Item = &'a String> {
+
+        let mut filter = BloomFilter {
+            bits: vec![0u64; BLOOM_BITS / 64],
+        };
+
+        for word in words {
+            let (h1, h2) = hash_pair(word.as_bytes());
+            for i in 0..BLOOM_HASHES {
+                let bit = bit_index(h1, h2, i);
+                filter.bits[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+
+        filter
+    }
+
+    /// Check whether `word` may be in the filter
+    ///
+    /// Always probes all `BLOOM_HASHES` positions, so the cost doesn't depend on which bit
+    /// happens to be missing.
+    pub(crate) fn maybe_contains(&self, word: &str) -> bool {
+
+        let (h1, h2) = hash_pair(word.as_bytes());
+
+        let mut present = true;
+        for i in 0..BLOOM_HASHES {
+            let bit = bit_index(h1, h2, i);
+            present &= self.bits[bit / 64] & (1 << (bit % 64)) != 0;
+        }
+
+        present
+    }
+}
+
+/// Kirsch-Mitzenmacher double hashing, derives the `i`th probe from two base hashes
+fn bit_index(h1: u64, h2: u64, i: u64) -> usize {
+    (h1.wrapping_add(i.wrapping_mul(h2)) % BLOOM_BITS as u64) as usize
+}
+
+/// 64-bit FNV-1a, split into two halves to seed the double hashing
+fn hash_pair(input: &[u8]) -> (u64, u64) {
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in input {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    // the second hash must be odd so every probe lands on a different bit
+    (hash & 0xffff_ffff, (hash >> 32) | 1)
+}
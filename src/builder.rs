@@ -0,0 +1,198 @@
+use std::prelude::v1::*;
+
+use std::fmt;
+use std::mem;
+
+#[cfg(feature = "std")]
+use rand::{OsRng, Rng};
+
+use ::error::{Error, ErrorKind};
+use ::language::Language;
+use ::mnemonic::Mnemonic;
+use ::mnemonic_type::MnemonicType;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Named, chainable options for creating a [`Mnemonic`][Mnemonic]
+///
+/// The constructors on `Mnemonic` take the phrase length, language and password positionally,
+/// which is easy to get wrong when two of them are strings. Here each option is set by name and
+/// anything not set falls back to a default: [`MnemonicType::Type12Words`][MnemonicType],
+/// [`Language::English`][Language] and an empty password.
+///
+/// [`MnemonicBuilder::build()`][MnemonicBuilder::build()] encodes entropy set with
+/// [`MnemonicBuilder::entropy()`][MnemonicBuilder::entropy()], and
+/// [`MnemonicBuilder::generate()`][MnemonicBuilder::generate()] creates random entropy instead.
+/// Calling the one that doesn't match the options returns an `Error` of kind
+/// `ErrorKind::InvalidBuilderOptions`.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{MnemonicBuilder, MnemonicType, Language};
+///
+/// let mnemonic = MnemonicBuilder::new()
+///     .mnemonic_type(MnemonicType::Type24Words)
+///     .language(Language::Japanese)
+///     .password("TREZOR")
+///     .generate()
+///     .unwrap();
+///
+/// assert_eq!(mnemonic.word_count(), 24);
+/// ```
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
+/// [Language]: ../language/enum.Language.html
+/// [MnemonicBuilder::build()]: ../builder/struct.MnemonicBuilder.html#method.build
+/// [MnemonicBuilder::entropy()]: ../builder/struct.MnemonicBuilder.html#method.entropy
+/// [MnemonicBuilder::generate()]: ../builder/struct.MnemonicBuilder.html#method.generate
+///
+#[derive(Clone, Default)]
+pub struct MnemonicBuilder {
+    mnemonic_type: Option<MnemonicType>,
+    language: Language,
+    password: String,
+    entropy: Option<Vec<u8>>,
+}
+
+impl MnemonicBuilder {
+
+    /// Create a builder with every option at its default
+    pub fn new() -> MnemonicBuilder {
+
+        MnemonicBuilder::default()
+    }
+
+    /// Set the number of words in the phrase
+    ///
+    /// When entropy is supplied this is optional, since the entropy length already decides it, but
+    /// if it is set the two have to agree.
+    pub fn mnemonic_type(mut self, mnemonic_type: MnemonicType) -> MnemonicBuilder {
+
+        self.mnemonic_type = Some(mnemonic_type);
+        self
+    }
+
+    /// Set the language of the word list the phrase is written in
+    pub fn language(mut self, language: Language) -> MnemonicBuilder {
+
+        self.language = language;
+        self
+    }
+
+    /// Set the password the [`Seed`][Seed] is derived with
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn password<S>(mut self, password: S) -> MnemonicBuilder where S: Into<String> {
+
+        self.password = password.into();
+        self
+    }
+
+    /// Set the entropy to encode, for use with [`MnemonicBuilder::build()`][MnemonicBuilder::build()]
+    ///
+    /// [MnemonicBuilder::build()]: ../builder/struct.MnemonicBuilder.html#method.build
+    pub fn entropy<E>(mut self, entropy: E) -> MnemonicBuilder where E: Into<Vec<u8>> {
+
+        self.entropy = Some(entropy.into());
+        self
+    }
+
+    /// Create the [`Mnemonic`][Mnemonic] for the entropy set with [`MnemonicBuilder::entropy()`][MnemonicBuilder::entropy()]
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidBuilderOptions` if no entropy was set,
+    /// `ErrorKind::InvalidEntropyBits` if the entropy isn't a standard length, whether or not a
+    /// mnemonic type was set, and `ErrorKind::InvalidEntropyLength` if it is a standard length but
+    /// doesn't match the mnemonic type that was set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::MnemonicBuilder;
+    ///
+    /// let entropy = vec![0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    ///
+    /// let mnemonic = MnemonicBuilder::new().entropy(entropy).build().unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [MnemonicBuilder::entropy()]: ../builder/struct.MnemonicBuilder.html#method.entropy
+    pub fn build(mut self) -> Result<Mnemonic, Error> {
+
+        let entropy = match self.entropy.take() {
+            Some(entropy) => entropy,
+            None => {
+                return Err(ErrorKind::InvalidBuilderOptions("no entropy was set, use generate() for a random phrase".to_string()).into())
+            }
+        };
+
+        // checked before the mnemonic type, so a non-standard length gives the same error with or
+        // without one
+        let entropy_type = MnemonicType::for_entropy_bits(entropy.len() * 8)?;
+        let mnemonic_type = self.mnemonic_type.unwrap_or(entropy_type);
+
+        Mnemonic::from_entropy_owned(entropy, mnemonic_type, self.language, mem::take(&mut self.password))
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from new random entropy, using the operating system RNG
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidBuilderOptions` if entropy was set with
+    /// [`MnemonicBuilder::entropy()`][MnemonicBuilder::entropy()], since it would be silently
+    /// ignored otherwise.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [MnemonicBuilder::entropy()]: ../builder/struct.MnemonicBuilder.html#method.entropy
+    #[cfg(feature = "std")]
+    pub fn generate(self) -> Result<Mnemonic, Error> {
+
+        let mut rng = OsRng::new()?;
+
+        self.generate_with_rng(&mut rng)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from new random entropy taken from `rng`
+    ///
+    /// Works like [`MnemonicBuilder::generate()`][MnemonicBuilder::generate()], see
+    /// [`Mnemonic::new_with_rng()`][Mnemonic::new_with_rng()] for choosing an RNG.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [MnemonicBuilder::generate()]: ../builder/struct.MnemonicBuilder.html#method.generate
+    /// [Mnemonic::new_with_rng()]: ../mnemonic/struct.Mnemonic.html#method.new_with_rng
+    #[cfg(feature = "std")]
+    pub fn generate_with_rng<R>(mut self, rng: &mut R) -> Result<Mnemonic, Error> where R: Rng {
+
+        if self.entropy.is_some() {
+            return Err(ErrorKind::InvalidBuilderOptions("entropy was set, use build() to encode it".to_string()).into())
+        }
+
+        let mnemonic_type = self.mnemonic_type.unwrap_or_default();
+
+        Mnemonic::new_with_rng(mnemonic_type, self.language, mem::take(&mut self.password), rng)
+    }
+}
+
+/// Only shows the options that aren't secret
+impl fmt::Debug for MnemonicBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MnemonicBuilder")
+            .field("mnemonic_type", &self.mnemonic_type)
+            .field("language", &self.language)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Clears the entropy and password when the `zeroize` feature is enabled
+#[cfg(feature = "zeroize")]
+impl Drop for MnemonicBuilder {
+    fn drop(&mut self) {
+        self.password.zeroize();
+
+        if let Some(entropy) = self.entropy.as_mut() {
+            entropy.zeroize();
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use rand::Rng;
+
+use ::crypto::PBKDF2_ROUNDS;
+use ::error::Error;
+use ::mnemonic::{Mnemonic, WordList};
+use ::mnemonic_type::MnemonicType;
+use ::seed::Seed;
+
+/// How a [`Bip39Context`][Bip39Context] treats phrases passed to it
+///
+/// [Bip39Context]: ../context/struct.Bip39Context.html
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Normalization {
+    /// Words must be spelled exactly as they are in the word list, see
+    /// [`Mnemonic::from_string()`][Mnemonic::from_string()]. Stray whitespace around and between
+    /// the words is still ignored.
+    ///
+    /// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
+    #[default]
+    Strict,
+    /// Surrounding quotes, brackets, extra separators and capitals are cleaned up first, see
+    /// [`Mnemonic::from_string_lenient()`][Mnemonic::from_string_lenient()]
+    ///
+    /// [Mnemonic::from_string_lenient()]: ../mnemonic/struct.Mnemonic.html#method.from_string_lenient
+    Lenient,
+}
+
+/// A word list, phrase length, parsing policy and RNG, configured once and used for many operations
+///
+/// Apps that create and check a lot of phrases otherwise end up passing the same word list and
+/// RNG to every call. The free functions on [`Mnemonic`][Mnemonic] are still there for one-off use.
+///
+/// Seeds are derived with [`Bip39Context::seed()`][Bip39Context::seed()], which uses the
+/// PBKDF2 iteration count set with
+/// [`Bip39Context::with_iterations()`][Bip39Context::with_iterations()], or the standard 2048 if
+/// none was set.
+///
+/// # Example
+///
+/// ```
+/// extern crate rand;
+/// extern crate bip39;
+///
+/// use bip39::{Bip39Context, MnemonicType, Normalization, Language};
+/// use rand::OsRng;
+///
+/// # fn main() {
+/// let word_list = Language::English.word_list().clone();
+///
+/// let mut context = Bip39Context::new(word_list, MnemonicType::Type24Words, Normalization::Lenient, OsRng::new().unwrap());
+///
+/// let mnemonic = context.generate("").unwrap();
+///
+/// assert!(context.validate(mnemonic.as_str()).is_ok());
+/// # }
+/// ```
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Bip39Context::seed()]: ../context/struct.Bip39Context.html#method.seed
+/// [Bip39Context::with_iterations()]: ../context/struct.Bip39Context.html#method.with_iterations
+///
+#[derive(Debug)]
+pub struct Bip39Context<R> {
+    word_list: Arc<WordList>,
+    mnemonic_type: MnemonicType,
+    normalization: Normalization,
+    // `None` for the 2048 rounds BIP39 requires
+    iterations: Option<u32>,
+    rng: R,
+}
+
+impl<R> Bip39Context<R> where R: Rng {
+
+    /// Create a new context
+    ///
+    /// `mnemonic_type` is only used for generating phrases, phrases of any valid length are
+    /// accepted by [`Bip39Context::validate()`][Bip39Context::validate()] and
+    /// [`Bip39Context::parse()`][Bip39Context::parse()].
+    ///
+    /// [Bip39Context::validate()]: ../context/struct.Bip39Context.html#method.validate
+    /// [Bip39Context::parse()]: ../context/struct.Bip39Context.html#method.parse
+    pub fn new(word_list: WordList,
+               mnemonic_type: MnemonicType,
+               normalization: Normalization,
+               rng: R) -> Bip39Context<R> {
+
+        Bip39Context {
+            word_list: Arc::new(word_list),
+            mnemonic_type,
+            normalization,
+            iterations: None,
+            rng,
+        }
+    }
+
+    /// Derive seeds with `iterations` rounds of PBKDF2 instead of the 2048 BIP39 requires
+    ///
+    /// **Only 2048 rounds is BIP39**, see
+    /// [`Seed::generate_with_iterations()`][Seed::generate_with_iterations()]. Only affects
+    /// [`Bip39Context::seed()`][Bip39Context::seed()], a [`Mnemonic`][Mnemonic] returned by the
+    /// context still derives the standard seed from `Mnemonic::as_seed()`.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Seed::generate_with_iterations()]: ../seed/struct.Seed.html#method.generate_with_iterations
+    /// [Bip39Context::seed()]: ../context/struct.Bip39Context.html#method.seed
+    pub fn with_iterations(mut self, iterations: u32) -> Bip39Context<R> {
+
+        self.iterations = Some(iterations);
+
+        self
+    }
+
+    /// Generate a new random [`Mnemonic`][Mnemonic]
+    ///
+    /// Like [`Mnemonic::new_lazy()`][Mnemonic::new_lazy()], the seed isn't derived until it is
+    /// first requested.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::new_lazy()]: ../mnemonic/struct.Mnemonic.html#method.new_lazy
+    pub fn generate<S>(&mut self, password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+        Mnemonic::new_lazy_shared(self.mnemonic_type, self.word_list.clone(), password.into(), &mut self.rng)
+    }
+
+    /// Validate a phrase against the context word list
+    pub fn validate<S>(&self, phrase: S) -> Result<(), Error> where S: Into<String> {
+
+        let m = phrase.into();
+
+        match self.normalization {
+            Normalization::Strict => Mnemonic::validate_with_word_list(m, &self.word_list),
+            Normalization::Lenient => Mnemonic::validate_with_word_list(Mnemonic::lenient_phrase(&m), &self.word_list)
+        }
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from an existing phrase
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn parse<S, P>(&self, phrase: S, password: P) -> Result<Mnemonic, Error>
+        where S: Into<String>, P: Into<String> {
+
+        let m = phrase.into();
+
+        // every mnemonic parsed here shares the context's copy of the word list
+        let m = match self.normalization {
+            Normalization::Strict => m,
+            Normalization::Lenient => Mnemonic::lenient_phrase(&m)
+        };
+
+        Mnemonic::from_string_shared(m, self.word_list.clone(), password.into())
+    }
+
+    /// Derive the seed of a [`Mnemonic`][Mnemonic] and `password` with the context's iteration count
+    ///
+    /// A `Mnemonic` doesn't keep its password once its own seed has been derived, so it is passed
+    /// again here. Without an override this is the standard seed, the same one
+    /// `Mnemonic::get_seed()` returns for a mnemonic created with `password`. An override of 0
+    /// returns an `Error` of kind `ErrorKind::InvalidIterations`.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn seed(&self, mnemonic: &Mnemonic, password: &str) -> Result<Seed, Error> {
+
+        Seed::generate_with_iterations(mnemonic.as_str(), password, self.iterations.unwrap_or(PBKDF2_ROUNDS))
+    }
+
+    /// Get the PBKDF2 iteration count override, `None` if seeds use the standard 2048
+    pub fn iterations(&self) -> Option<u32> {
+        self.iterations
+    }
+
+    /// Get the word list used by this context
+    pub fn word_list(&self) -> &WordList {
+        &self.word_list
+    }
+}
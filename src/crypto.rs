@@ -6,52 +6,181 @@
 //!
 
 
+use std::prelude::v1::*;
+
+#[cfg(feature = "std")]
+use ring::constant_time::verify_slices_are_equal;
+#[cfg(feature = "std")]
 use ring::digest::{self, digest};
+#[cfg(feature = "std")]
+use ring::hmac;
+#[cfg(feature = "std")]
 use ring::pbkdf2;
 
-extern crate rand;
-use self::rand::{OsRng, Rng};
+#[cfg(feature = "std")]
+use rand::Rng;
 
-use ::error::Error;
+#[cfg(not(feature = "std"))]
+use ::hashes::{self, Hmac, Sha256, Sha512};
 
-static PBKDF2_ROUNDS: u32 = 2048;
-static PBKDF2_BYTES: usize = 64;
+/// Number of PBKDF2 rounds BIP39 uses to derive a seed
+pub(crate) static PBKDF2_ROUNDS: u32 = 2048;
+/// Length of a BIP39 seed in bytes
+pub(crate) static PBKDF2_BYTES: usize = 64;
+#[cfg(feature = "altcoin")]
+static PBKDF2_SHA256_BYTES: usize = 32;
 
 
-/// SHA256 helper function, internal to the crate
+/// SHA256 helper function, used for the mnemonic checksum
+///
+/// Exported from the crate root with the `crypto-utils` feature, so tools working with BIP39
+/// checksums can use exactly the same implementation as the crate, `ring` with the `std` feature
+/// and the portable one in `hashes` without it.
 ///
-pub(crate) fn sha256(input: &[u8]) -> Vec<u8> {
+#[cfg(feature = "std")]
+pub fn sha256(input: &[u8]) -> Vec<u8> {
 
-    static DIGEST_ALG: &'static digest::Algorithm = &digest::SHA256;
+    static DIGEST_ALG: &digest::Algorithm = &digest::SHA256;
 
     let hash = digest(DIGEST_ALG, input);
 
     hash.as_ref().to_vec()
 }
 
-/// Random byte generator, used to create new mnemonics
+#[cfg(not(feature = "std"))]
+pub fn sha256(input: &[u8]) -> Vec<u8> {
+
+    hashes::digest::<Sha256>(input)
+}
+
+/// SHA512 helper function, using the same implementation as the seed derivation
+///
+/// Only available with the `crypto-utils` feature.
+///
+#[cfg(all(feature = "crypto-utils", feature = "std"))]
+pub fn sha512(input: &[u8]) -> Vec<u8> {
+
+    static DIGEST_ALG: &digest::Algorithm = &digest::SHA512;
+
+    let hash = digest(DIGEST_ALG, input);
+
+    hash.as_ref().to_vec()
+}
+
+#[cfg(all(feature = "crypto-utils", not(feature = "std")))]
+pub fn sha512(input: &[u8]) -> Vec<u8> {
+
+    hashes::digest::<Sha512>(input)
+}
+
+/// Random byte generator using a caller supplied RNG, used to create new mnemonics
+///
+#[cfg(feature = "std")]
+pub(crate) fn gen_random_bytes_with<R>(rng: &mut R, byte_length: usize) -> Vec<u8> where R: Rng {
+
+    rng.gen_iter::<u8>().take(byte_length).collect::<Vec<u8>>()
+}
+
+/// Compare two byte slices in constant time, so how long it takes doesn't reveal where they
+/// differ
+///
+/// Slices of different lengths are unequal, only the length is revealed then.
+///
+#[cfg(feature = "std")]
+pub(crate) fn constant_time_eq(a: &[u8],
+                               b: &[u8]) -> bool {
+
+    verify_slices_are_equal(a, b).is_ok()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn constant_time_eq(a: &[u8],
+                               b: &[u8]) -> bool {
+
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// HMAC-SHA512 helper, used to derive the BIP32 master key from a [`Seed`][Seed]
 ///
-pub(crate) fn gen_random_bytes(byte_length: usize) -> Result<Vec<u8>, Error> {
+/// [Seed]: ../seed/struct.Seed.html
+///
+#[cfg(feature = "std")]
+pub(crate) fn hmac_sha512(key: &[u8],
+                          data: &[u8]) -> Vec<u8> {
+
+    let signing_key = hmac::SigningKey::new(&digest::SHA512, key);
+
+    hmac::sign(&signing_key, data).as_ref().to_vec()
+}
 
-    let mut rng = OsRng::new()?;
-    let entropy = rng.gen_iter::<u8>().take(byte_length).collect::<Vec<u8>>();
+#[cfg(not(feature = "std"))]
+pub(crate) fn hmac_sha512(key: &[u8],
+                          data: &[u8]) -> Vec<u8> {
 
-    Ok(entropy)
+    Hmac::<Sha512>::new(key).sign(data)
 }
 
 /// PBKDF2 helper, used to generate [`Seed`][Seed] from [`Mnemonic`][Mnemonic]
 ///
+/// BIP39 uses [`PBKDF2_ROUNDS`][PBKDF2_ROUNDS] rounds, other counts are only for callers that
+/// explicitly ask for them. Panics if `rounds` is 0.
+///
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 /// [Seed]: ../seed/struct.Seed.html
+/// [PBKDF2_ROUNDS]: ./static.PBKDF2_ROUNDS.html
 /// 
+#[cfg(feature = "std")]
+pub(crate) fn pbkdf2(input: &[u8],
+              salt: String,
+              rounds: u32) -> Vec<u8> {
+
+    let mut seed = vec![0u8; PBKDF2_BYTES];
+
+    static DIGEST_ALG: &digest::Algorithm = &digest::SHA512;
+
+    // a single call runs all the rounds inside ring, this version of ring takes the round count
+    // as a plain u32 rather than a NonZeroU32, and asserts that it isn't 0
+    pbkdf2::derive(DIGEST_ALG, rounds, salt.as_bytes(), input, &mut seed);
+
+    seed
+}
+
+#[cfg(not(feature = "std"))]
 pub(crate) fn pbkdf2(input: &[u8],
-              salt: String) -> Vec<u8> {
+              salt: String,
+              rounds: u32) -> Vec<u8> {
 
     let mut seed = vec![0u8; PBKDF2_BYTES];
 
-    static DIGEST_ALG: &'static digest::Algorithm = &digest::SHA512;
+    hashes::pbkdf2::<Sha512>(input, salt.as_bytes(), rounds, &mut seed);
+
+    seed
+}
+
+/// PBKDF2-HMAC-SHA256 helper, used for the non-standard seed some altcoins derive
+///
+/// Only available with the `altcoin` feature.
+///
+#[cfg(all(feature = "altcoin", feature = "std"))]
+pub(crate) fn pbkdf2_sha256(input: &[u8],
+                            salt: String) -> Vec<u8> {
+
+    let mut seed = vec![0u8; PBKDF2_SHA256_BYTES];
+
+    static DIGEST_ALG: &digest::Algorithm = &digest::SHA256;
 
     pbkdf2::derive(DIGEST_ALG, PBKDF2_ROUNDS, salt.as_bytes(), input, &mut seed);
 
     seed
 }
+
+#[cfg(all(feature = "altcoin", not(feature = "std")))]
+pub(crate) fn pbkdf2_sha256(input: &[u8],
+                            salt: String) -> Vec<u8> {
+
+    let mut seed = vec![0u8; PBKDF2_SHA256_BYTES];
+
+    hashes::pbkdf2::<Sha256>(input, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+
+    seed
+}
@@ -1,39 +1,224 @@
+// error_chain's generated impls still use the deprecated `description`/`cause` methods
+#![allow(deprecated)]
+
+use std::prelude::v1::*;
+
+#[cfg(not(feature = "std"))]
+use std::fmt;
+
 use mnemonic_type::MnemonicType;
 
-error_chain! {
-    foreign_links {
-        EntropyUnavailable(::std::io::Error);
-        DataDecode(::data_encoding::DecodeError);
-    }
+/// Defines `Error` and `ErrorKind` from one list of error kinds
+///
+/// With the `std` feature the list goes to `error_chain!`. Without it, error-chain isn't available
+/// and the same kinds become a plain enum, with `Error` wrapping it.
+macro_rules! bip39_errors {
+    ($($kind:ident $(($($field:ident: $field_type:ty),*))? {
+        description($description:expr)
+        display($($display:tt)*)
+    })*) => {
+        #[cfg(feature = "std")]
+        error_chain! {
+            foreign_links {
+                EntropyUnavailable(::std::io::Error);
+                DataDecode(::data_encoding::DecodeError);
+                WordListParse(::serde_json::Error);
+                BitRead(::bitreader::BitReaderError);
+            }
 
-    errors {
-        InvalidChecksum {
-            description("invalid checksum")
-            display("Invalid checksum")
-        }
-        InvalidWord {
-            description("invalid word in phrase")
-            display("Invalid word in phrase")
-        }
-        InvalidKeysize {
-            description("invalid keysize")
-            display("Invalid keysize")
-        }
-        InvalidWordLength {
-            description("invalid number of words in phrase")
-            display("Invalid number of words in phrase")
-        }
-        InvalidEntropyLength(entropy_length_bits: usize, mnemonic_type: MnemonicType) {
-            description("invalid entropy length for mnemonic type")
-            display("Invalid entropy length {}bits for mnemonic type {}", entropy_length_bits, mnemonic_type)
+            errors {
+                $($kind $(($($field: $field_type),*))? {
+                    description($description)
+                    display($($display)*)
+                })*
+            }
         }
-        InvalidFile {
-            description("invalid file")
-            display("Unable to read file from path")
+
+        /// The kind of an [`Error`][Error]
+        ///
+        /// [Error]: ./struct.Error.html
+        #[cfg(not(feature = "std"))]
+        #[derive(Debug)]
+        pub enum ErrorKind {
+            DataDecode(::data_encoding::DecodeError),
+            WordListParse(::serde_json::Error),
+            BitRead(::bitreader::BitReaderError),
+            $($kind $(($($field_type),*))?,)*
         }
-        LanguageUnavailable {
-            description("wrapping key failed")
-            display("Language unavailable")
+
+        #[cfg(not(feature = "std"))]
+        impl fmt::Display for ErrorKind {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match *self {
+                    ErrorKind::DataDecode(ref e) => fmt::Display::fmt(e, f),
+                    ErrorKind::WordListParse(ref e) => fmt::Display::fmt(e, f),
+                    ErrorKind::BitRead(ref e) => fmt::Display::fmt(e, f),
+                    $(ErrorKind::$kind $(($(ref $field),*))? => write!(f, $($display)*),)*
+                }
+            }
         }
     }
 }
+
+bip39_errors! {
+    InvalidChecksum(expected: u8, found: u8) {
+        description("invalid checksum")
+        display("Invalid checksum, expected {:#04x} but found {:#04x}", expected, found)
+    }
+    InvalidWord(word: String, index: usize) {
+        description("invalid word in phrase")
+        display("Invalid word \"{}\" at position {}", word, index)
+    }
+    InvalidWordIndex(index: u16) {
+        description("invalid word index")
+        display("Invalid word index {}", index)
+    }
+    UnknownPrefix(prefix: String) {
+        description("prefix does not match any word")
+        display("Prefix \"{}\" does not match any word", prefix)
+    }
+    AmbiguousPrefix(prefix: String) {
+        description("prefix matches more than one word")
+        display("Prefix \"{}\" matches more than one word", prefix)
+    }
+    PrefixTooShort(prefix: String) {
+        description("prefix is shorter than 4 characters")
+        display("Prefix \"{}\" is too short, at least 4 characters are needed", prefix)
+    }
+    InvalidKeysize {
+        description("invalid keysize")
+        display("Invalid keysize")
+    }
+    InvalidEntropyBits(bits: usize) {
+        description("invalid number of entropy bits")
+        display("Invalid entropy size {}bits, must be a multiple of 32 from 128 to 256", bits)
+    }
+    InvalidWordLength {
+        description("invalid number of words in phrase")
+        display("Invalid number of words in phrase")
+    }
+    InvalidWordCount(count: usize) {
+        description("invalid number of words in phrase")
+        display("Invalid number of words {}, must be 12, 15, 18, 21 or 24", count)
+    }
+    InvalidMnemonicType(input: String) {
+        description("invalid mnemonic type")
+        display("Invalid mnemonic type \"{}\", expected something like \"12 words (128bits)\"", input)
+    }
+    InvalidEntropyLength(entropy_length_bits: usize, mnemonic_type: MnemonicType) {
+        description("invalid entropy length for mnemonic type")
+        display("Invalid entropy length {}bits for mnemonic type {}", entropy_length_bits, mnemonic_type)
+    }
+    InvalidDiceRoll(roll: u8) {
+        description("invalid dice roll")
+        display("Invalid dice roll {}, must be from 1 to 6", roll)
+    }
+    NotEnoughDiceRolls(found: usize, required: usize) {
+        description("not enough dice rolls for mnemonic type")
+        display("Got {} dice rolls but at least {} are needed", found, required)
+    }
+    InvalidSeedLength(hex_length: usize) {
+        description("invalid seed length")
+        display("Invalid seed hex length {}, must be 128 characters", hex_length)
+    }
+    InvalidIterations {
+        description("invalid number of PBKDF2 iterations")
+        display("Invalid number of PBKDF2 iterations 0, must be at least 1")
+    }
+    InvalidMasterKey {
+        description("seed gives an invalid BIP32 master key")
+        display("Seed gives an invalid BIP32 master key")
+    }
+    InvalidWordList(reason: String) {
+        description("invalid word list")
+        display("Invalid word list: {}", reason)
+    }
+    InvalidBuilderOptions(reason: String) {
+        description("invalid combination of builder options")
+        display("Invalid builder options: {}", reason)
+    }
+    InvalidFile {
+        description("invalid file")
+        display("Unable to read file from path")
+    }
+    TestVectorMismatch(field: String, expected: String, found: String) {
+        description("test vector mismatch")
+        display("Test vector {} mismatch, expected {} but found {}", field, expected, found)
+    }
+    SearchExhausted(attempts: usize) {
+        description("no matching mnemonic found")
+        display("No matching mnemonic found after {} attempts", attempts)
+    }
+    LanguageMismatch(expected: String, found: String) {
+        description("word list is for a different language")
+        display("Expected a word list for {} but got one for {}", expected, found)
+    }
+    NoMatchingLanguage(failures: Vec<String>) {
+        description("phrase is not valid in any of the given languages")
+        display("Phrase is not valid in any of the given languages ({})", failures.join("; "))
+    }
+    CorruptedMnemonic {
+        description("mnemonic state is corrupted")
+        display("Mnemonic is corrupted, its phrase and entropy no longer match")
+    }
+    LanguageUnavailable {
+        description("wrapping key failed")
+        display("Language unavailable")
+    }
+}
+
+/// The error type for everything in this crate that can fail
+///
+/// Only used without the `std` feature, with it this is generated by `error_chain!`. It has the
+/// same shape, so code matching on `Error(kind, _)` works in both builds.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct Error(pub ErrorKind, pub State);
+
+/// Stands in for error-chain's state, which holds the backtrace and the error that caused this one
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Default)]
+pub struct State;
+
+#[cfg(not(feature = "std"))]
+impl Error {
+    /// Get the kind of this error
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error(kind, State)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<::data_encoding::DecodeError> for Error {
+    fn from(e: ::data_encoding::DecodeError) -> Error {
+        Error(ErrorKind::DataDecode(e), State)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<::serde_json::Error> for Error {
+    fn from(e: ::serde_json::Error) -> Error {
+        Error(ErrorKind::WordListParse(e), State)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<::bitreader::BitReaderError> for Error {
+    fn from(e: ::bitreader::BitReaderError) -> Error {
+        Error(ErrorKind::BitRead(e), State)
+    }
+}
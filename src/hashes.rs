@@ -0,0 +1,301 @@
+//! Portable SHA-256 and SHA-512, with HMAC and PBKDF2 built on them, used in place of `ring`
+//! without the `std` feature.
+//!
+//! They follow FIPS 180-4, RFC 2104 and RFC 8018 as written and favour being easy to check over
+//! speed. The known answer tests in `tests/no_std.rs` and `tests/crypto.rs` run against them when
+//! the tests are built with `--no-default-features`.
+//!
+
+use std::prelude::v1::*;
+
+/// A hash function that HMAC and PBKDF2 can be built on
+pub(crate) trait Hash: Clone {
+    /// Size of the blocks the input is processed in, in bytes
+    const BLOCK_LEN: usize;
+    /// Size of the digest, in bytes
+    const OUTPUT_LEN: usize;
+
+    fn new() -> Self;
+
+    fn update(&mut self, data: &[u8]);
+
+    fn finish(self) -> Vec<u8>;
+}
+
+/// Digest `input` with `H` in one go
+pub(crate) fn digest<H>(input: &[u8]) -> Vec<u8> where H: Hash {
+
+    let mut hash = H::new();
+    hash.update(input);
+    hash.finish()
+}
+
+// SHA-256 and SHA-512 only differ in their word size, constants and rotation amounts
+macro_rules! sha2 {
+    (
+        $name:ident,
+        word: $word:ty,
+        length: $length:ty,
+        block: $block_len:expr,
+        output: $output_len:expr,
+        initial: $initial:expr,
+        rounds: $k:expr,
+        big_sigma0: ($a0:expr, $a1:expr, $a2:expr),
+        big_sigma1: ($e0:expr, $e1:expr, $e2:expr),
+        small_sigma0: ($w0:expr, $w1:expr, $w2:expr),
+        small_sigma1: ($x0:expr, $x1:expr, $x2:expr)
+    ) => {
+        #[derive(Clone)]
+        pub(crate) struct $name {
+            state: [$word; 8],
+            buffer: [u8; $block_len],
+            buffered: usize,
+            // total input length in bytes
+            length: $length,
+        }
+
+        impl $name {
+
+            fn compress(state: &mut [$word; 8], block: &[u8]) {
+
+                const WORD_LEN: usize = ::std::mem::size_of::<$word>();
+
+                let mut w = [0 as $word; $k.len()];
+                for (word, bytes) in w.iter_mut().zip(block.chunks_exact(WORD_LEN)) {
+                    let mut be = [0u8; WORD_LEN];
+                    be.copy_from_slice(bytes);
+                    *word = <$word>::from_be_bytes(be);
+                }
+                for i in 16..w.len() {
+                    let s0 = w[i - 15].rotate_right($w0) ^ w[i - 15].rotate_right($w1) ^ (w[i - 15] >> $w2);
+                    let s1 = w[i - 2].rotate_right($x0) ^ w[i - 2].rotate_right($x1) ^ (w[i - 2] >> $x2);
+                    w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+                }
+
+                let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+                for (k, w) in $k.iter().zip(w.iter()) {
+                    let s1 = e.rotate_right($e0) ^ e.rotate_right($e1) ^ e.rotate_right($e2);
+                    let ch = (e & f) ^ (!e & g);
+                    let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(*w);
+                    let s0 = a.rotate_right($a0) ^ a.rotate_right($a1) ^ a.rotate_right($a2);
+                    let maj = (a & b) ^ (a & c) ^ (b & c);
+                    let t2 = s0.wrapping_add(maj);
+
+                    h = g;
+                    g = f;
+                    f = e;
+                    e = d.wrapping_add(t1);
+                    d = c;
+                    c = b;
+                    b = a;
+                    a = t1.wrapping_add(t2);
+                }
+
+                for (word, add) in state.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+                    *word = word.wrapping_add(*add);
+                }
+            }
+        }
+
+        impl Hash for $name {
+            const BLOCK_LEN: usize = $block_len;
+            const OUTPUT_LEN: usize = $output_len;
+
+            fn new() -> $name {
+
+                $name {
+                    state: $initial,
+                    buffer: [0; $block_len],
+                    buffered: 0,
+                    length: 0,
+                }
+            }
+
+            fn update(&mut self, mut data: &[u8]) {
+
+                self.length = self.length.wrapping_add(data.len() as $length);
+
+                if self.buffered > 0 {
+                    let take = ($block_len - self.buffered).min(data.len());
+                    self.buffer[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+                    self.buffered += take;
+                    data = &data[take..];
+
+                    if self.buffered < $block_len {
+                        return
+                    }
+
+                    $name::compress(&mut self.state, &self.buffer);
+                    self.buffered = 0;
+                }
+
+                let mut blocks = data.chunks_exact($block_len);
+                for block in &mut blocks {
+                    $name::compress(&mut self.state, block);
+                }
+
+                let rest = blocks.remainder();
+                self.buffer[..rest.len()].copy_from_slice(rest);
+                self.buffered = rest.len();
+            }
+
+            fn finish(mut self) -> Vec<u8> {
+
+                const LENGTH_LEN: usize = ::std::mem::size_of::<$length>();
+
+                let bit_length = self.length.wrapping_mul(8);
+
+                // a 1 bit, then zeros up to the length field at the end of the last block
+                let mut padding = [0u8; $block_len];
+                padding[0] = 0x80;
+                let end = $block_len - LENGTH_LEN;
+                let padding_len = if self.buffered < end { end - self.buffered } else { end + $block_len - self.buffered };
+
+                self.update(&padding[..padding_len]);
+                self.update(&bit_length.to_be_bytes());
+
+                self.state.iter().flat_map(|word| word.to_be_bytes()).take($output_len).collect()
+            }
+        }
+    }
+}
+
+sha2!(
+    Sha256,
+    word: u32,
+    length: u64,
+    block: 64,
+    output: 32,
+    initial: [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ],
+    rounds: SHA256_K,
+    big_sigma0: (2, 13, 22),
+    big_sigma1: (6, 11, 25),
+    small_sigma0: (7, 18, 3),
+    small_sigma1: (17, 19, 10)
+);
+
+sha2!(
+    Sha512,
+    word: u64,
+    length: u128,
+    block: 128,
+    output: 64,
+    initial: [
+        0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+        0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+    ],
+    rounds: SHA512_K,
+    big_sigma0: (28, 34, 39),
+    big_sigma1: (14, 18, 41),
+    small_sigma0: (1, 8, 7),
+    small_sigma1: (19, 61, 6)
+);
+
+/// HMAC keyed with one key, for signing any number of messages with it
+///
+/// The key is absorbed into the inner and outer hash states once, PBKDF2 signs thousands of
+/// messages with the same key.
+pub(crate) struct Hmac<H> {
+    inner: H,
+    outer: H,
+}
+
+impl<H> Hmac<H> where H: Hash {
+
+    pub(crate) fn new(key: &[u8]) -> Hmac<H> {
+
+        // keys longer than a block are hashed first, shorter ones are padded with zeros
+        let mut block = vec![0u8; H::BLOCK_LEN];
+        if key.len() > H::BLOCK_LEN {
+            let hashed = digest::<H>(key);
+            block[..hashed.len()].copy_from_slice(&hashed);
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = H::new();
+        inner.update(&block.iter().map(|byte| byte ^ 0x36).collect::<Vec<u8>>());
+
+        let mut outer = H::new();
+        outer.update(&block.iter().map(|byte| byte ^ 0x5c).collect::<Vec<u8>>());
+
+        Hmac { inner, outer }
+    }
+
+    pub(crate) fn sign(&self, data: &[u8]) -> Vec<u8> {
+
+        let mut inner = self.inner.clone();
+        inner.update(data);
+
+        let mut outer = self.outer.clone();
+        outer.update(&inner.finish());
+        outer.finish()
+    }
+}
+
+/// Fill `output` with PBKDF2, using HMAC with `H` as the pseudorandom function
+///
+/// Panics if `rounds` is 0, like `ring`.
+pub(crate) fn pbkdf2<H>(password: &[u8],
+                        salt: &[u8],
+                        rounds: u32,
+                        output: &mut [u8]) where H: Hash {
+
+    assert!(rounds > 0, "PBKDF2 needs at least one round");
+
+    let prf = Hmac::<H>::new(password);
+
+    for (i, chunk) in output.chunks_mut(H::OUTPUT_LEN).enumerate() {
+        let mut first = salt.to_vec();
+        first.extend_from_slice(&(i as u32 + 1).to_be_bytes());
+
+        let mut u = prf.sign(&first);
+        let mut block = u.clone();
+
+        for _ in 1..rounds {
+            u = prf.sign(&u);
+            for (byte, next) in block.iter_mut().zip(u.iter()) {
+                *byte ^= next;
+            }
+        }
+
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const SHA512_K: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
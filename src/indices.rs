@@ -0,0 +1,202 @@
+//! Conversion between entropy and the 11-bit word indices that make up a mnemonic phrase.
+//!
+//! These work purely on numbers and never touch a word list, which makes them useful for
+//! interop with hardware wallets and other tools that deal in word indices rather than strings.
+//!
+
+use std::prelude::v1::*;
+
+use bitreader::BitReader;
+
+use ::crypto::{constant_time_eq, sha256};
+use ::error::{Error, ErrorKind};
+use ::mnemonic_type::MnemonicType;
+use ::util::bit_from_u16_as_u11;
+
+/// Number of words in a BIP39 word list, every index must be below this
+pub(crate) const WORD_COUNT: u16 = 2048;
+
+/// Convert entropy to the word indices of its mnemonic phrase, checksum included
+///
+/// The entropy must be the right length for `mnemonic_type`, otherwise an `Error` of kind
+/// `ErrorKind::InvalidEntropyLength` is returned.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{entropy_to_indices, MnemonicType};
+///
+/// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+///
+/// let indices = entropy_to_indices(entropy, MnemonicType::Type12Words).unwrap();
+///
+/// assert_eq!(indices.len(), 12);
+/// ```
+pub fn entropy_to_indices(entropy: &[u8],
+                          mnemonic_type: MnemonicType) -> Result<Vec<u16>, Error> {
+
+    owned_entropy_to_indices(Vec::from(entropy), mnemonic_type)
+}
+
+/// Same as [`entropy_to_indices()`][entropy_to_indices()], but reuses the entropy allocation
+///
+/// [entropy_to_indices()]: ./fn.entropy_to_indices.html
+pub(crate) fn owned_entropy_to_indices(entropy: Vec<u8>,
+                                       mnemonic_type: MnemonicType) -> Result<Vec<u16>, Error> {
+    let entropy_length_bits = entropy.len() * 8;
+
+    if entropy_length_bits != mnemonic_type.entropy_bits() {
+        return Err(ErrorKind::InvalidEntropyLength(entropy_length_bits, mnemonic_type).into())
+    }
+
+    let num_words = mnemonic_type.word_count();
+
+    let entropy_hash = sha256(&entropy);
+
+    // we put both the entropy and the hash of the entropy (in that order) into a single vec
+    // and then just read 11 bits at a time out of the entire thing `num_words` times. We
+    // can do that because:
+    //
+    // 12 words * 11bits = 132bits
+    // 15 words * 11bits = 165bits
+    //
+    // ... and so on. It grabs the entropy and then the right number of hash bits and no more.
+
+    let mut combined = entropy;
+    combined.extend(&entropy_hash);
+
+    let mut reader = BitReader::new(&combined);
+
+    let mut indices: Vec<u16> = Vec::with_capacity(num_words);
+    for _ in 0..num_words {
+        // the buffer always holds enough bits for valid entropy lengths, but a read past the end
+        // is still reported rather than panicking
+        indices.push(reader.read_u16(11)?);
+    }
+
+    Ok(indices)
+}
+
+/// Convert word indices back to the entropy they encode, verifying the checksum
+///
+/// This is the inverse of [`entropy_to_indices()`][entropy_to_indices()]. The number of indices
+/// must match `mnemonic_type`, otherwise an `Error` of kind `ErrorKind::InvalidWordCount` is
+/// returned with the number of indices given. Indices outside the 2048 word range return
+/// `ErrorKind::InvalidWordIndex`, and a checksum mismatch returns `ErrorKind::InvalidChecksum`.
+///
+/// `InvalidChecksum` carries the checksum computed from the entropy as `expected` and the one
+/// carried by the indices as `found`. Both are the 4 to 8 checksum bits as a number, so for a 12
+/// word phrase they are from 0 to 15. Any wrong word changes the entropy and with it the whole
+/// expected checksum, so they don't point at the wrong word, but they are useful for logging and
+/// for checking a phrase by hand.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{entropy_to_indices, indices_to_entropy, MnemonicType};
+///
+/// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+///
+/// let indices = entropy_to_indices(entropy, MnemonicType::Type12Words).unwrap();
+/// let decoded = indices_to_entropy(&indices, MnemonicType::Type12Words).unwrap();
+///
+/// assert_eq!(&entropy[..], &decoded[..]);
+/// ```
+///
+/// [entropy_to_indices()]: ./fn.entropy_to_indices.html
+pub fn indices_to_entropy(indices: &[u16],
+                          mnemonic_type: MnemonicType) -> Result<Vec<u8>, Error> {
+
+    let (entropy, found) = split_indices(indices, mnemonic_type)?;
+
+    let expected = checksum(&entropy, mnemonic_type);
+
+    // compare in constant time so how long validation takes doesn't reveal how many of the
+    // checksum bits were right
+    if !constant_time_eq(&[expected], &[found]) {
+        return Err(ErrorKind::InvalidChecksum(expected, found).into())
+    }
+
+    Ok(entropy)
+}
+
+/// Split word indices into the entropy they encode and the checksum they carry, without
+/// verifying the checksum
+///
+/// Returns the same errors as [`indices_to_entropy()`][indices_to_entropy()] for the wrong number
+/// of indices or an index outside the word list range.
+///
+/// [indices_to_entropy()]: ./fn.indices_to_entropy.html
+pub(crate) fn split_indices(indices: &[u16],
+                            mnemonic_type: MnemonicType) -> Result<(Vec<u8>, u8), Error> {
+
+    if indices.len() != mnemonic_type.word_count() {
+        return Err(ErrorKind::InvalidWordCount(indices.len()).into())
+    }
+
+    if let Some(n) = indices.iter().find(|n| **n >= WORD_COUNT) {
+        return Err(ErrorKind::InvalidWordIndex(*n).into())
+    }
+
+    let packed = pack_indices(indices);
+    let entropy_bytes = mnemonic_type.entropy_bits() / 8;
+
+    // the checksum is at most 8 bits, so it always sits in the byte right after the entropy
+    let found = packed[entropy_bytes] >> (8 - mnemonic_type.checksum_bits());
+
+    Ok((packed[..entropy_bytes].to_vec(), found))
+}
+
+/// Pack word indices into bytes, 11 bits each, most significant bit first
+///
+/// Every index has to be below 2048 and there can be at most 24 of them, 24 words * 11 bits =
+/// 264 bits is the most any phrase needs.
+fn pack_indices(indices: &[u16]) -> [u8; 33] {
+
+    let mut packed = [0u8; 33];
+
+    for (i, n) in indices.iter().enumerate() {
+        for bit in 0..11 {
+            if bit_from_u16_as_u11(*n, bit) {
+                let position = i * 11 + bit as usize;
+                packed[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+    }
+
+    packed
+}
+
+/// The checksum bits for `entropy`, as a number
+///
+/// The checksum is the first `checksum_bits` bits of the SHA256 hash of the entropy, which is at
+/// most 8 bits, so it always fits in a `u8`.
+pub(crate) fn checksum(entropy: &[u8],
+                       mnemonic_type: MnemonicType) -> u8 {
+
+    sha256(entropy)[0] >> (8 - mnemonic_type.checksum_bits())
+}
+
+/// Check whether the checksum carried by `indices` matches the entropy they encode
+///
+/// Skips building the entropy and an error, since this is meant for tight loops that try many
+/// candidate index sets. Returns `false` for the wrong number of indices or any index outside the
+/// word list range.
+pub(crate) fn checksum_matches(indices: &[u16],
+                               mnemonic_type: MnemonicType) -> bool {
+
+    if indices.len() != mnemonic_type.word_count() || indices.iter().any(|n| *n >= WORD_COUNT) {
+        return false
+    }
+
+    let packed = pack_indices(indices);
+
+    let entropy_bytes = mnemonic_type.entropy_bits() / 8;
+
+    // the checksum is at most 8 bits, so it always sits in the byte right after the entropy
+    let shift = 8 - mnemonic_type.checksum_bits();
+
+    let hash = sha256(&packed[..entropy_bytes]);
+
+    constant_time_eq(&[packed[entropy_bytes] >> shift], &[hash[0] >> shift])
+}
@@ -0,0 +1,256 @@
+use std::prelude::v1::*;
+
+use unicode_normalization::UnicodeNormalization;
+
+use ::mnemonic::WordList;
+use ::sync::{Arc, OnceLock};
+use ::util::split_phrase;
+
+static ENGLISH: OnceLock<Arc<WordList>> = OnceLock::new();
+static JAPANESE: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-spanish")]
+static SPANISH: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-french")]
+static FRENCH: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-italian")]
+static ITALIAN: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-czech")]
+static CZECH: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-korean")]
+static KOREAN: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-chinese-simplified")]
+static CHINESE_SIMPLIFIED: OnceLock<Arc<WordList>> = OnceLock::new();
+#[cfg(feature = "lang-chinese-traditional")]
+static CHINESE_TRADITIONAL: OnceLock<Arc<WordList>> = OnceLock::new();
+
+/// Parse one of the word lists embedded in the crate
+///
+/// These are part of the crate itself, so a list that fails to parse or doesn't have exactly 2048
+/// distinct words is a bug in the build rather than something callers can recover from.
+///
+/// The bloom filter is built here as well, so every phrase checked against a built in language
+/// uses the same precomputed filter, including the first one.
+fn parse_word_list(json: &str) -> Arc<WordList> {
+
+    let word_list = WordList::from_json(json).expect("embedded word list is invalid");
+    word_list.bloom_filter();
+
+    Arc::new(word_list)
+}
+
+/// The language of a BIP39 word list
+///
+/// English and Japanese are always available. Spanish, French, Italian, Czech, Korean and both
+/// Chinese scripts are each behind a cargo feature named after the language, like `lang-spanish`
+/// or `lang-chinese-simplified`, so their word lists are only compiled in when needed.
+/// Portuguese is not embedded yet, its official list can be loaded with
+/// [`WordList::from_text_reader()`][WordList::from_text_reader()], which needs the `std` feature.
+///
+/// Each variant maps to a word list that is embedded in the crate at compile time, so no
+/// filesystem access is needed. The list is parsed once, the first time it is used, and shared
+/// after that. A [`WordList`][WordList] loaded from a file can be
+/// mapped to a `Language` with [`WordList::as_language()`][WordList::as_language()].
+///
+/// [WordList]: ../mnemonic/struct.WordList.html
+/// [WordList::as_language()]: ../mnemonic/struct.WordList.html#method.as_language
+/// [WordList::from_text_reader()]: ../mnemonic/struct.WordList.html#method.from_text_reader
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Japanese,
+    #[cfg(feature = "lang-spanish")]
+    Spanish,
+    #[cfg(feature = "lang-french")]
+    French,
+    #[cfg(feature = "lang-italian")]
+    Italian,
+    #[cfg(feature = "lang-czech")]
+    Czech,
+    #[cfg(feature = "lang-korean")]
+    Korean,
+    /// Simplified Chinese, the words are at the same positions as in `ChineseTraditional`
+    #[cfg(feature = "lang-chinese-simplified")]
+    ChineseSimplified,
+    /// Traditional Chinese, the words are at the same positions as in `ChineseSimplified`
+    #[cfg(feature = "lang-chinese-traditional")]
+    ChineseTraditional
+}
+
+/// Every supported language, in the order [`Language::detect()`][Language::detect()] tries them
+///
+/// [Language::detect()]: ../language/enum.Language.html#method.detect
+static ALL: &[Language] = &[
+    Language::English,
+    Language::Japanese,
+    #[cfg(feature = "lang-spanish")]
+    Language::Spanish,
+    #[cfg(feature = "lang-french")]
+    Language::French,
+    #[cfg(feature = "lang-italian")]
+    Language::Italian,
+    #[cfg(feature = "lang-czech")]
+    Language::Czech,
+    #[cfg(feature = "lang-korean")]
+    Language::Korean,
+    #[cfg(feature = "lang-chinese-simplified")]
+    Language::ChineseSimplified,
+    #[cfg(feature = "lang-chinese-traditional")]
+    Language::ChineseTraditional,
+];
+
+impl Language {
+    /// Get the word list for this language
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// assert_eq!(word_list.words.len(), 2048);
+    /// ```
+    pub fn word_list(&self) -> &'static WordList {
+
+        self.shared()
+    }
+
+    /// Get the word list for this language without copying it, for storing in a `Mnemonic`
+    pub(crate) fn shared_word_list(&self) -> Arc<WordList> {
+
+        self.shared().clone()
+    }
+
+    fn shared(&self) -> &'static Arc<WordList> {
+
+        let (word_list, json) = match *self {
+            Language::English => (&ENGLISH, include_str!("english.json")),
+            Language::Japanese => (&JAPANESE, include_str!("japanese.json")),
+            #[cfg(feature = "lang-spanish")]
+            Language::Spanish => (&SPANISH, include_str!("spanish.json")),
+            #[cfg(feature = "lang-french")]
+            Language::French => (&FRENCH, include_str!("french.json")),
+            #[cfg(feature = "lang-italian")]
+            Language::Italian => (&ITALIAN, include_str!("italian.json")),
+            #[cfg(feature = "lang-czech")]
+            Language::Czech => (&CZECH, include_str!("czech.json")),
+            #[cfg(feature = "lang-korean")]
+            Language::Korean => (&KOREAN, include_str!("korean.json")),
+            #[cfg(feature = "lang-chinese-simplified")]
+            Language::ChineseSimplified => (&CHINESE_SIMPLIFIED, include_str!("chinese_simplified.json")),
+            #[cfg(feature = "lang-chinese-traditional")]
+            Language::ChineseTraditional => (&CHINESE_TRADITIONAL, include_str!("chinese_traditional.json"))
+        };
+
+        word_list.get_or_init(|| parse_word_list(json))
+    }
+
+    /// Get the separator placed between words in a phrase for this language
+    ///
+    /// BIP39 phrases are joined with an ASCII space, except Japanese which uses the ideographic
+    /// space `U+3000`. Phrases are split on any whitespace, including both of these, regardless
+    /// of language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.separator(), " ");
+    /// assert_eq!(Language::Japanese.separator(), "\u{3000}");
+    /// ```
+    pub fn separator(&self) -> &'static str {
+
+        match *self {
+            Language::Japanese => "\u{3000}",
+            _ => " "
+        }
+    }
+
+    /// Check whether every one of `words` is in this language's word list
+    ///
+    /// This is [`WordList::contains_all()`][WordList::contains_all()] on the list from
+    /// [`Language::word_list()`][Language::word_list()], it stops at the first word that isn't in
+    /// the list. Words are compared exactly, they are not normalized first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert!(Language::English.contains_all(&["park", "remain", "person"]));
+    /// assert!(!Language::English.contains_all(&["park", "rester", "person"]));
+    /// ```
+    ///
+    /// [WordList::contains_all()]: ../mnemonic/struct.WordList.html#method.contains_all
+    /// [Language::word_list()]: ../language/enum.Language.html#method.word_list
+    pub fn contains_all(&self, words: &[&str]) -> bool {
+
+        self.word_list().contains_all(words)
+    }
+
+    /// Guess the language of a phrase from its words
+    ///
+    /// Each embedded word list is tried in turn and the first one that contains every word of the
+    /// phrase is returned, or `None` if no list contains all of them. Words are NFKD normalized
+    /// before lookup, so composed input like a Japanese phrase typed on a phone still matches.
+    ///
+    /// Only word membership is checked, not the number of words or the checksum, so a phrase that
+    /// is detected still has to be validated. A phrase whose words appear in more than one list
+    /// is reported as the first of them, in declaration order of the variants.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// assert_eq!(Language::detect(phrase), Some(Language::English));
+    /// assert_eq!(Language::detect("park remain personne"), None);
+    /// ```
+    pub fn detect(phrase: &str) -> Option<Language> {
+
+        let words: Vec<String> = split_phrase(phrase).map(|word| word.nfkd().collect()).collect();
+
+        if words.is_empty() {
+            return None
+        }
+
+        let words: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+
+        ALL.iter()
+           .find(|language| language.contains_all(&words))
+           .cloned()
+    }
+
+    /// Get the `Language` for a word list language name, like the `language` field of a word list file
+    ///
+    /// Names are matched ignoring ASCII case, unknown names return `None`.
+    pub(crate) fn from_name(name: &str) -> Option<Language> {
+
+        let language = match name.to_ascii_lowercase().as_ref() {
+            "english" => Language::English,
+            "japanese" => Language::Japanese,
+            #[cfg(feature = "lang-spanish")]
+            "spanish" => Language::Spanish,
+            #[cfg(feature = "lang-french")]
+            "french" => Language::French,
+            #[cfg(feature = "lang-italian")]
+            "italian" => Language::Italian,
+            #[cfg(feature = "lang-czech")]
+            "czech" => Language::Czech,
+            #[cfg(feature = "lang-korean")]
+            "korean" => Language::Korean,
+            #[cfg(feature = "lang-chinese-simplified")]
+            "chinese_simplified" => Language::ChineseSimplified,
+            #[cfg(feature = "lang-chinese-traditional")]
+            "chinese_traditional" => Language::ChineseTraditional,
+            _ => { return None }
+        };
+
+        Some(language)
+    }
+}
@@ -8,19 +8,13 @@
 //! ## Quickstart
 //!
 //! ```rust
-//! use bip39::{Mnemonic, MnemonicType, Seed};
-//! use std::path::PathBuf;
-//! use std::env;
+//! use bip39::{Mnemonic, MnemonicType, Language, Seed};
 //!
 //! /// determines the number of words in the mnemonic phrase
 //! let mnemonic_type = MnemonicType::Type12Words;
-//! 
-//! /// get the path where a language .json file is located
-//! let mut path = PathBuf::from(env::current_dir().unwrap());
-//! path.push("src/english.json");
-//! 
+//!
 //! /// create a new randomly generated mnemonic phrase
-//! let mnemonic = match Mnemonic::new(mnemonic_type, path, "") {
+//! let mnemonic = match Mnemonic::new(mnemonic_type, Language::English, "") {
 //!     Ok(b) => b,
 //!     Err(e) => { println!("e: {}", e); return }
 //! };
@@ -43,26 +37,99 @@
 //!
 //! ```
 //!
+//! ## `no_std`
+//!
+//! With the default `std` feature turned off the crate only needs `alloc`. Phrases can still be
+//! created from entropy, parsed, validated and turned into seeds, but random generation, loading
+//! word lists or test vectors from files, `Bip39Context` and the `mlock` feature need `std`.
+//!
+
+// error_chain! expands recursively, once per error kind
+#![recursion_limit = "256"]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use] extern crate alloc;
+#[cfg(feature = "std")]
 #[macro_use] extern crate error_chain;
-#[macro_use] extern crate lazy_static;
 extern crate data_encoding;
 extern crate bitreader;
-extern crate bit_vec;
+#[cfg(feature = "std")]
 extern crate ring;
+#[cfg(feature = "std")]
+extern crate rand;
 extern crate serde;
 extern crate serde_json;
 #[macro_use] extern crate serde_derive;
+extern crate unicode_normalization;
+#[cfg(feature = "mlock")]
+extern crate region;
+#[cfg(feature = "mlock")]
+#[macro_use] extern crate log;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+
+/// The parts of `std` the crate uses, taken from `core` and `alloc`, so the same `std::` paths
+/// work without the `std` feature
+#[cfg(not(feature = "std"))]
+mod std {
+    pub use core::*;
+    pub use alloc::{borrow, collections, fmt};
+
+    pub mod sync {
+        pub use alloc::sync::*;
+        pub use core::sync::atomic;
+    }
+
+    pub mod prelude {
+        pub mod v1 {
+            pub use core::prelude::v1::*;
+            pub use alloc::borrow::ToOwned;
+            pub use alloc::boxed::Box;
+            pub use alloc::string::{String, ToString};
+            pub use alloc::vec::Vec;
+        }
+    }
+}
 
 mod mnemonic;
 mod error;
 mod mnemonic_type;
 mod util;
 mod seed;
+mod bloom;
+mod vectors;
+mod indices;
+mod language;
+mod validation;
+mod blocklist;
+#[cfg(feature = "std")]
+mod context;
+mod master_key;
+mod builder;
+mod sync;
 
 mod crypto;
+#[cfg(not(feature = "std"))]
+mod hashes;
+#[cfg(feature = "mlock")]
+mod locked;
 
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{Mnemonic, WordList};
+pub use builder::MnemonicBuilder;
+#[cfg(feature = "std")]
+pub use context::{Bip39Context, Normalization};
 pub use mnemonic_type::MnemonicType;
-pub use seed::Seed;
+pub use language::Language;
+pub use seed::{Seed, WalletFormat};
+#[cfg(feature = "altcoin")]
+pub use seed::Sha256Seed;
+pub use master_key::MasterKey;
+pub use vectors::TestVector;
+pub use validation::{validate_phrase, ChecksumStatus, ValidationReport};
+pub use indices::{entropy_to_indices, indices_to_entropy};
+
+#[cfg(feature = "crypto-utils")]
+pub use crypto::{sha256, sha512};
 pub use error::Error;
 pub use error::ErrorKind;
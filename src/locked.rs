@@ -0,0 +1,128 @@
+//! A byte buffer that is locked into RAM, so the secret it holds is never written to swap.
+//!
+//! Only used for the [`Seed`][Seed] bytes, and only with the `mlock` feature.
+//!
+//! [Seed]: ../seed/struct.Seed.html
+//!
+
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::ops::Deref;
+use std::ptr;
+use std::slice;
+
+use region::{self, LockGuard};
+
+pub(crate) struct LockedBytes {
+    // start of a page aligned allocation of whole pages, that no other value shares
+    ptr: *mut u8,
+    len: usize,
+    layout: Layout,
+    // `None` if the OS refused to lock the pages, the bytes are still usable
+    guard: Option<LockGuard>,
+}
+
+// the allocation is owned by this value alone, like the buffer of a Vec<u8>
+unsafe impl Send for LockedBytes {}
+unsafe impl Sync for LockedBytes {}
+
+impl LockedBytes {
+
+    /// Copy `bytes` into their own pages and lock them, then clear `bytes`
+    ///
+    /// mlock works on whole pages and isn't reference counted, unlocking a page unlocks it for
+    /// everything on it. Giving every buffer pages of its own means dropping one seed can never
+    /// unlock another that happened to share a page with it.
+    ///
+    /// Locking can fail, for example when `RLIMIT_MEMLOCK` is too low or the platform doesn't
+    /// support it. That is logged as a warning and the bytes are kept unlocked rather than failing
+    /// the whole operation.
+    fn new(mut bytes: Vec<u8>) -> LockedBytes {
+
+        let page_size = region::page::size();
+        let size = bytes.len().max(1).div_ceil(page_size) * page_size;
+        let layout = Layout::from_size_align(size, page_size).expect("page size is a power of two");
+
+        let ptr = unsafe { alloc::alloc_zeroed(layout) };
+        if ptr.is_null() {
+            alloc::handle_alloc_error(layout);
+        }
+
+        let guard = match region::lock(ptr, size) {
+            Ok(guard) => Some(guard),
+            Err(e) => {
+                warn!("unable to lock seed memory, it may be swapped to disk: {}", e);
+                None
+            }
+        };
+
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len()) };
+        clear(&mut bytes);
+
+        LockedBytes {
+            ptr,
+            len: bytes.len(),
+            layout,
+            guard,
+        }
+    }
+
+    /// Whether the memory is actually locked
+    pub(crate) fn is_locked(&self) -> bool {
+        self.guard.is_some()
+    }
+}
+
+/// Overwrite `bytes` with zeros, volatile writes so the compiler can't drop them as dead stores
+fn clear(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+}
+
+impl From<Vec<u8>> for LockedBytes {
+    fn from(bytes: Vec<u8>) -> LockedBytes {
+        LockedBytes::new(bytes)
+    }
+}
+
+impl Deref for LockedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl AsRef<[u8]> for LockedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Clone for LockedBytes {
+    fn clone(&self) -> LockedBytes {
+        LockedBytes::new(self.to_vec())
+    }
+}
+
+/// Doesn't show the bytes, they are always a secret
+impl fmt::Debug for LockedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LockedBytes")
+            .field("len", &self.len)
+            .field("locked", &self.is_locked())
+            .finish()
+    }
+}
+
+impl Drop for LockedBytes {
+    fn drop(&mut self) {
+        // clear the secret before the pages are unlocked and handed back to the allocator
+        clear(unsafe { slice::from_raw_parts_mut(self.ptr, self.layout.size()) });
+
+        self.guard.take();
+
+        unsafe { alloc::dealloc(self.ptr, self.layout) };
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The BIP32 master private key and chain code for a [`Seed`][Seed], from [`Seed::to_master_key()`][Seed::to_master_key()]
+///
+/// This is the first step of BIP32 HD wallet derivation, every other key in the wallet is derived
+/// from these two values. Deriving child keys and encoding them as `xprv` strings needs elliptic
+/// curve arithmetic, which is left to a BIP32 crate.
+///
+/// [Seed]: ../seed/struct.Seed.html
+/// [Seed::to_master_key()]: ../seed/struct.Seed.html#method.to_master_key
+///
+#[derive(Clone)]
+pub struct MasterKey {
+    /// The secp256k1 private key
+    pub private_key: [u8; 32],
+    /// The chain code that child keys are derived with
+    pub chain_code: [u8; 32],
+}
+
+/// Doesn't show the key or chain code
+impl fmt::Debug for MasterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MasterKey").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for MasterKey {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
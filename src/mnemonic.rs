@@ -1,21 +1,64 @@
 extern crate serde_json;
 
+use std::prelude::v1::*;
+
+#[cfg(feature = "std")]
 use std::path::PathBuf;
+#[cfg(feature = "std")]
 use std::fs::File;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "std")]
+use std::io::Read;
+use std::str::FromStr;
+use std::borrow::Cow;
+use std::fmt;
+use std::convert::TryFrom;
 
 use serde_json::de;
 
-use bitreader::BitReader;
-use bit_vec::BitVec;
+use data_encoding::{HEXLOWER, HEXUPPER};
+
+#[cfg(feature = "std")]
+use rand::{OsRng, Rng};
 
-use data_encoding::HEXUPPER;
+use unicode_normalization::UnicodeNormalization;
 
-use ::crypto::{gen_random_bytes, sha256};
+use ::blocklist::KNOWN_TEST_ENTROPY;
+use ::bloom::BloomFilter;
+use ::crypto::constant_time_eq;
+#[cfg(feature = "std")]
+use ::crypto::{gen_random_bytes_with, sha256};
 use ::error::{Error, ErrorKind};
 use ::mnemonic_type::MnemonicType;
-use ::util::bit_from_u16_as_u11;
-use ::seed::Seed;
+use ::indices::{checksum, checksum_matches, entropy_to_indices, indices_to_entropy, owned_entropy_to_indices, split_indices};
+use ::language::Language;
+use ::seed::{LazySeed, Seed};
+use ::sync::{Arc, OnceLock};
+use ::util::{levenshtein, nfkd, normalize_whitespace, prefix_form, split_phrase};
+use ::validation::{validate_phrase, ChecksumStatus, ValidationReport};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serialize")]
+use serde::de::Error as DeserializeError;
+
+/// Word to index map of a [`WordList`][WordList], without std there is no `HashMap`
+///
+/// [WordList]: ../mnemonic/struct.WordList.html
+#[cfg(feature = "std")]
+type WordMap = HashMap<String, u16>;
+#[cfg(not(feature = "std"))]
+type WordMap = BTreeMap<String, u16>;
+
+/// Most words [`WordList::nearest_words()`][WordList::nearest_words()] returns
+///
+/// [WordList::nearest_words()]: ../mnemonic/struct.WordList.html#method.nearest_words
+const MAX_SUGGESTIONS: usize = 10;
 
 /// The primary type in this crate, most tasks require creating or using one.
 ///
@@ -42,391 +85,2535 @@ use ::seed::Seed;
 /// [Seed::as_bytes()]: ./seed/struct.Seed.html#method.as_bytes
 /// [Seed::as_hex()]: ./seed/struct.Seed.html#method.as_hex
 ///
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Mnemonic {
     string: String,
-    seed: Seed,
-    word_list: WordList,
+    // holds the password until the seed is derived
+    seed: LazySeed,
+    words: OnceLock<Vec<String>>,
+    word_list: Arc<WordList>,
     entropy: Vec<u8>,
 }
 
+/// A list of 2048 words that phrases are built from
+///
+/// The built in lists are available from [`Language::word_list()`][Language::word_list()]. Other
+/// lists can be loaded from JSON with [`WordList::from_reader()`][WordList::from_reader()], in the
+/// same format as `src/english.json`, or built directly with
+/// [`WordList::from_words()`][WordList::from_words()].
+///
+/// [Language::word_list()]: ../language/enum.Language.html#method.word_list
+/// [WordList::from_reader()]: ../mnemonic/struct.WordList.html#method.from_reader
+/// [WordList::from_words()]: ../mnemonic/struct.WordList.html#method.from_words
+///
 #[derive(Debug, Clone, Deserialize)]
 pub struct WordList {
     pub language: String,
-    pub words: Vec<String>
+    pub words: Vec<String>,
+    #[serde(skip)]
+    bloom: OnceLock<BloomFilter>,
+    #[serde(skip)]
+    sorted: OnceLock<Vec<u16>>,
+    #[serde(skip)]
+    word_map: OnceLock<WordMap>,
+    #[serde(skip)]
+    prefix_words: OnceLock<Vec<String>>,
 }
 
 impl WordList {
-    pub fn gen_wordmap(&self) -> HashMap<String, u16> {
-
-        let mut word_map: HashMap<String, u16> = HashMap::new();
-        for (i, item) in self.words.clone().into_iter().enumerate() {
-            word_map.insert(item.to_owned(), i as u16);
-        }
-        word_map
-    }
-}
-
-impl Mnemonic {
-
-    /// Generates a new `Mnemonic`
-    ///
-    /// Can be used to get the [`Seed`][Seed] using [`Mnemonic::get_seed()`][Mnemonic::get_seed()].
+    /// Load a word list from JSON, like the files in `src/`
     ///
-    /// Can also be used to get the original entropy value. Use [`Mnemonic::as_entropy()`][Mnemonic::as_entropy()] for a slice, or
-    /// [Mnemonic::get_entropy()][Mnemonic::get_entropy()] for an owned `Vec<u8>`.
+    /// The JSON must be an object with a `language` name and an array of `words`, malformed JSON
+    /// returns an `Error` of kind `ErrorKind::WordListParse` with the parser's message. The words are
+    /// checked the same way as [`WordList::from_words()`][WordList::from_words()].
     ///
+    /// # Example
     ///
-    /// use bip39::{Mnemonic, MnemonicType};
-    /// use std::path::PathBuf;
+    /// ```
+    /// use bip39::WordList;
     /// use std::fs::File;
-    /// use std::env;
-    ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
     ///
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
+    /// let file = File::open("src/english.json").unwrap();
     ///
-    /// let mnemonic = match Mnemonic::new(mnemonic_type, path, "") {
-    ///     Ok(b) => b,
-    ///     Err(e) => { println!("e: {}", e); return }
-    /// };
+    /// let word_list = WordList::from_reader(file).unwrap();
     ///
-    /// let phrase = mnemonic.get_string();
-    /// let seed = mnemonic.get_seed();
-    /// let seed_bytes: &[u8] = seed.as_bytes();
-    /// let seed_hex: &str = seed.as_hex();
-    /// println!("phrase: {}", phrase);
+    /// assert_eq!(word_list.language, "english");
     /// ```
-    /// [Seed]: ../seed/struct.Seed.html
-    /// [Mnemonic::get_seed()]: ./mnemonic/struct.Mnemonic.html#method.get_seed
-    /// [Mnemonic::as_entropy()]: ./mnemonic/struct.Mnemonic.html#method.as_entropy
-    /// [Mnemonic::get_entropy()]: ./mnemonic/struct.Mnemonic.html#method.get_entropy
-    pub fn new<S>(mnemonic_type: MnemonicType,
-                  path: PathBuf,
-                  password: S) -> Result<Mnemonic, Error> where S: Into<String> {
-
-        let word_list: WordList = Mnemonic::get_word_list(path).unwrap();
-
-        let entropy_bits = mnemonic_type.entropy_bits();
+    ///
+    /// [WordList::from_words()]: ../mnemonic/struct.WordList.html#method.from_words
+    #[cfg(feature = "std")]
+    pub fn from_reader<R>(reader: R) -> Result<WordList, Error> where R: Read {
 
-        let entropy = gen_random_bytes(entropy_bits / 8)?;
+        let word_list: WordList = de::from_reader(reader)?;
 
-        Mnemonic::from_entropy(&entropy, mnemonic_type, &word_list, password)
+        WordList::from_words(word_list.language, word_list.words)
     }
 
-    /// Create a [`Mnemonic`][Mnemonic] from generated entropy
+    /// Load a word list from the plain text format of the official BIP39 repository
+    ///
+    /// The text has one word per line, in index order. Each line is trimmed, so `\r\n` line endings
+    /// work, and blank lines at the end are skipped. The words are then checked the same way as
+    /// [`WordList::from_words()`][WordList::from_words()]. Text that can't be read, or isn't
+    /// UTF-8, returns an `Error` of kind `ErrorKind::InvalidWordList`.
     ///
     /// # Example
     ///
     /// ```
-    /// use bip39::{Mnemonic, MnemonicType};
-    /// use std::path::PathBuf;
+    /// use bip39::{Language, WordList};
     /// use std::fs::File;
-    /// use std::env;
     ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
-    ///
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
-    /// 
-    /// let word_list = Mnemonic::get_word_list(path).unwrap();
+    /// let file = File::open("tests/wordlists/english.txt").unwrap();
     ///
-    /// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
-    /// let mnemonic = Mnemonic::from_entropy(entropy, MnemonicType::for_key_size(128).unwrap(), &word_list, "").unwrap();
+    /// let word_list = WordList::from_text_reader("english".to_owned(), file).unwrap();
     ///
-    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// assert_eq!(word_list.words, Language::English.word_list().words);
     /// ```
     ///
-    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-    pub fn from_entropy<S>(entropy: &[u8],
-                           mnemonic_type: MnemonicType,
-                           word_list: &WordList,
-                           password: S) -> Result<Mnemonic, Error> where S: Into<String> {
-        let entropy_length_bits = entropy.len() * 8;
+    /// [WordList::from_words()]: ../mnemonic/struct.WordList.html#method.from_words
+    #[cfg(feature = "std")]
+    pub fn from_text_reader<R>(language: String,
+                               mut reader: R) -> Result<WordList, Error> where R: Read {
 
-        if entropy_length_bits != mnemonic_type.entropy_bits() {
-            return Err(ErrorKind::InvalidEntropyLength(entropy_length_bits, mnemonic_type).into())
-        }
-
-        let num_words = mnemonic_type.word_count();
+        let mut text = String::new();
+        reader.read_to_string(&mut text)
+            .map_err(|e| Error::from(ErrorKind::InvalidWordList(format!("unable to read text: {}", e))))?;
 
-        let entropy_hash = sha256(entropy);
+        let mut words: Vec<String> = text.lines().map(|line| line.trim().to_owned()).collect();
 
-        // we put both the entropy and the hash of the entropy (in that order) into a single vec
-        // and then just read 11 bits at a time out of the entire thing `num_words` times. We
-        // can do that because:
-        //
-        // 12 words * 11bits = 132bits
-        // 15 words * 11bits = 165bits
-        //
-        // ... and so on. It grabs the entropy and then the right number of hash bits and no more.
-
-        let mut combined = Vec::from(entropy);
-        combined.extend(&entropy_hash);
+        while words.last().is_some_and(|word| word.is_empty()) {
+            words.pop();
+        }
 
-        let mut reader = BitReader::new(&combined);
+        WordList::from_words(language, words)
+    }
 
-        let mut words: Vec<&str> = Vec::new();
-        for _ in 0..num_words {
-            let n = reader.read_u16(11);
-            words.push(word_list.words[n.unwrap() as usize].as_ref());
-        }
+    /// Load a word list from JSON in a string, used for the lists embedded in the crate
+    pub(crate) fn from_json(json: &str) -> Result<WordList, Error> {
 
-        let string = words.join(" ");
+        let word_list: WordList = de::from_str(json)?;
 
-        Mnemonic::from_string(string, word_list.clone(), password.into())
+        WordList::from_words(word_list.language, word_list.words)
     }
 
-    /// Create a [`Mnemonic`][Mnemonic] from generated entropy hexadecimal representation
+    /// Build a word list from its words, in index order
+    ///
+    /// The words are NFKD normalized, the form BIP39 uses and the one the official lists are
+    /// written in, so phrases typed with composed characters are found in any list. They are then
+    /// checked with [`WordList::validate_structure()`][WordList::validate_structure()], a list that
+    /// fails it returns that `Error`.
     ///
     /// # Example
     ///
     /// ```
-    /// use bip39::{Mnemonic, MnemonicType};
-    /// use std::path::PathBuf;
-    /// use std::fs::File;
-    /// use std::env;
-    /// 
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
-    /// 
-    /// let word_list = Mnemonic::get_word_list(path).unwrap();
-    /// 
-    /// let entropy = "33E46BB13A746EA41CDDE45C90846A79";
-    /// let mnemonic = Mnemonic::from_entropy_hex(entropy, MnemonicType::for_key_size(128).unwrap(), &word_list, "").unwrap();
+    /// use bip39::{Language, WordList};
     ///
-    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// let mut words = Language::English.word_list().words.clone();
+    /// words.reverse();
+    ///
+    /// let reversed = WordList::from_words("reversed".to_owned(), words).unwrap();
+    ///
+    /// assert_eq!(reversed.words[0], "zoo");
+    /// assert!(WordList::from_words("short".to_owned(), vec!["abandon".to_owned()]).is_err());
     /// ```
     ///
-    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-    pub fn from_entropy_hex<S>(entropy: &str,
-                           mnemonic_type: MnemonicType,
-                           word_list: &WordList,
-                           password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+    /// [WordList::validate_structure()]: ../mnemonic/struct.WordList.html#method.validate_structure
+    pub fn from_words(language: String,
+                      words: Vec<String>) -> Result<WordList, Error> {
+
+        let words = words.into_iter().map(|word| if word.is_ascii() { word } else { word.nfkd().collect() }).collect();
+
+        let word_list = WordList {
+            language,
+            words,
+            bloom: OnceLock::new(),
+            sorted: OnceLock::new(),
+            word_map: OnceLock::new(),
+            prefix_words: OnceLock::new(),
+        };
 
-        Mnemonic::from_entropy(&HEXUPPER.decode(entropy.as_ref())?, mnemonic_type, &word_list, password)
+        word_list.validate_structure()?;
+
+        Ok(word_list)
     }
 
-    /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase
+    /// Check that this list can be used for BIP39 phrases
     ///
-    /// The phrase supplied will be checked for word length and validated according to the checksum
-    /// specified in BIP0039
+    /// The list needs exactly 2048 words, all distinct, and no two words may start with the same
+    /// 4 characters, which autocompletion and [`Mnemonic::from_prefixes()`][Mnemonic::from_prefixes()]
+    /// rely on. Characters are counted in NFKC form, the form every prefix is compared in, so an
+    /// accent is part of the character it sits on and a Hangul syllable is a single character. Any other list returns an `Error` of kind
+    /// `ErrorKind::InvalidWordList` naming the offending words.
+    ///
+    /// Every list is checked this way when it is built, this is for lists whose `words` were
+    /// changed afterwards.
     ///
     /// # Example
     ///
     /// ```
-    /// use bip39::Mnemonic;
-    /// use std::path::PathBuf;
-    /// use std::fs::File;
-    /// use std::env;
+    /// use bip39::Language;
     ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mut word_list = Language::English.word_list().clone();
+    /// assert!(word_list.validate_structure().is_ok());
     ///
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
-    /// 
-    /// let word_list = Mnemonic::get_word_list(path).unwrap();
-    /// 
-    /// let mnemonic = Mnemonic::from_string(test_mnemonic, word_list, "").unwrap();
+    /// word_list.words[1] = "abandoned".to_owned();
+    /// assert!(word_list.validate_structure().is_err());
     /// ```
     ///
-    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-    pub fn from_string<S>(string: S,
-                          word_list: WordList,
-                          password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+    /// [Mnemonic::from_prefixes()]: ../mnemonic/struct.Mnemonic.html#method.from_prefixes
+    pub fn validate_structure(&self) -> Result<(), Error> {
 
-        let m = string.into();
-        let p = password.into();
+        if self.words.len() != 2048 {
+            return Err(ErrorKind::InvalidWordList(format!("expected 2048 words, found {}", self.words.len())).into())
+        }
 
-        // this also validates the checksum and phrase length before returning the entropy so we
-        // can store it. We don't use the validate function here to avoid having a public API that
-        // takes a phrase string and returns the entropy directly. See the Mnemonic::entropy()
-        // docs for the reason.
-        let entropy = Mnemonic::entropy(&*m, &word_list)?;
-        let seed = Seed::generate(&m.as_bytes(), &p);
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        for word in &self.words {
+            if !seen.insert(word) {
+                return Err(ErrorKind::InvalidWordList(format!("\"{}\" appears more than once", word)).into())
+            }
+        }
 
-        let mnemonic = Mnemonic {
-            string: (&m).clone(),
-            seed,
-            word_list,
-            entropy
-        };
+        let mut prefixes: BTreeMap<String, &str> = BTreeMap::new();
+        for word in &self.words {
+            let prefix: String = prefix_form(word).chars().take(4).collect();
 
-        Ok(mnemonic)
+            if let Some(other) = prefixes.insert(prefix, word) {
+                return Err(ErrorKind::InvalidWordList(format!("\"{}\" and \"{}\" start with the same 4 characters", other, word)).into())
+            }
+        }
+
+        Ok(())
     }
 
-    /// Validate a mnemonic phrase
+    /// Get the [`Language`][Language] this list is for
     ///
-    /// The phrase supplied will be checked for word length and validated according to the checksum
-    /// specified in BIP0039
-    ///
-    /// Note: you cannot use this function to determine anything more than whether the mnemonic
-    /// phrase itself is intact, it does not check the password or compute the seed value. For that,
-    /// you should use [`Mnemonic::from_string()`][Mnemonic::from_string()].
+    /// Returns `None` if the `language` name isn't one the crate knows about, as is the case for
+    /// custom word lists.
     ///
     /// # Example
     ///
     /// ```
-    /// use bip39::Mnemonic;
-    /// use std::path::PathBuf;
-    /// use std::fs::File;
-    /// use std::env;
+    /// use bip39::Language;
     ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let word_list = Language::English.word_list();
     ///
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
-    /// 
-    /// let word_list = Mnemonic::get_word_list(path).unwrap();
-    /// 
-    /// match Mnemonic::validate(test_mnemonic, word_list) {
-    ///     Ok(_) => { println!("valid: {}", test_mnemonic); },
-    ///     Err(e) => { println!("e: {}", e); return }
-    /// }
+    /// assert_eq!(word_list.as_language(), Some(Language::English));
     /// ```
     ///
-    /// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
-    pub fn validate<S>(string: S,
-                       word_list: WordList) -> Result<(), Error> where S: Into<String> {
-        Mnemonic::entropy(string, &word_list).and(Ok(()))
+    /// [Language]: ../language/enum.Language.html
+    pub fn as_language(&self) -> Option<Language> {
+        Language::from_name(&self.language)
     }
 
-    /// Calculate the checksum, verify it and return the entropy
+    /// The separator to join words from this list with, an ASCII space for custom word lists
+    pub(crate) fn separator(&self) -> &'static str {
+        self.as_language().map_or(" ", |language| language.separator())
+    }
+
+    /// Fast check for whether `word` may be in this list
     ///
-    /// Only intended for internal use, as returning a `Vec<u8>` that looks a bit like it could be
-    /// used as the seed is likely to cause problems for someone eventually. All the other functions
-    /// that return something like that are explicit about what it is and what to use it for.
-    fn entropy<S>(string: S,
-                  word_list: &WordList) -> Result<Vec<u8>, Error> where S: Into<String> {
-        let m = string.into();
+    /// A `false` result means the word is definitely not in the list, a `true` result means it
+    /// probably is, with a false positive rate of roughly 0.1% for a standard 2048 word list. An
+    /// exact lookup is still done afterwards when validating a phrase, this only exists to reject
+    /// obviously invalid input (OCR noise, typos) cheaply.
+    ///
+    /// The filters for the built in [`Language`][Language]s are built along with their word lists,
+    /// for any other list it is built the first time this is called and reused afterwards. The
+    /// check itself always does the same fixed number of probes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// assert!(word_list.maybe_contains("abandon"));
+    /// ```
+    ///
+    /// [Language]: ../language/enum.Language.html
+    pub fn maybe_contains(&self, word: &str) -> bool {
+        self.bloom_filter().maybe_contains(word)
+    }
 
-        let mnemonic_type = MnemonicType::for_phrase(&*m)?;
-        let entropy_bits = mnemonic_type.entropy_bits();
-        let checksum_bits = mnemonic_type.checksum_bits();
+    /// Get the bloom filter for this list, building it if it doesn't exist yet
+    pub(crate) fn bloom_filter(&self) -> &BloomFilter {
+        self.bloom.get_or_init(|| BloomFilter::new(&self.words))
+    }
 
-        let word_map = word_list.gen_wordmap();
+    /// Check whether every one of `words` is in this list
+    ///
+    /// Stops at the first word that isn't in the list, so checking a phrase against the wrong
+    /// language is cheap. Lookups are a binary search over a sorted index of the list, built the
+    /// first time it is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// assert!(word_list.contains_all(&["park", "remain", "person"]));
+    /// assert!(!word_list.contains_all(&["park", "rester", "person"]));
+    /// ```
+    pub fn contains_all(&self, words: &[&str]) -> bool {
+        words.iter().all(|word| self.position(word).is_some())
+    }
 
-        let mut to_validate: BitVec = BitVec::new();
+    /// Find the index of `word` in the list
+    pub(crate) fn position(&self, word: &str) -> Option<u16> {
 
-        for word in m.split(" ").into_iter() {
-            let n = match word_map.get(word) {
-                Some(n) => n,
-                None => return Err(ErrorKind::InvalidWord.into())
-            };
-            for i in 0..11 {
-                let bit = bit_from_u16_as_u11(*n, i);
-                to_validate.push(bit);
-            }
+        if !self.maybe_contains(word) {
+            return None
         }
 
-        let mut checksum_to_validate = BitVec::new();
-        &checksum_to_validate.extend((&to_validate).into_iter().skip(entropy_bits).take(checksum_bits));
-        assert!(checksum_to_validate.len() == checksum_bits, "invalid checksum size");
+        let sorted = self.sorted.get_or_init(|| {
+            let mut sorted: Vec<u16> = (0..self.words.len() as u16).collect();
+            sorted.sort_by(|a, b| self.words[*a as usize].cmp(&self.words[*b as usize]));
+            sorted
+        });
+
+        sorted.binary_search_by(|n| self.words[*n as usize].as_str().cmp(word))
+            .ok()
+            .map(|i| sorted[i])
+    }
+
+    /// Get the shortest prefix length, in characters, that identifies each word in the list
+    ///
+    /// The result is in word list order. Words that are themselves the start of a longer word
+    /// (like `act` and `action`) get their full length, since typing the whole word is the only
+    /// way to pick them out. Characters are counted in NFKC form, like in
+    /// [`WordList::validate_structure()`][WordList::validate_structure()], which is why no entry
+    /// is ever longer than 4 for a list that passes it, including all the standard BIP39 lists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let prefixes = word_list.unique_prefixes();
+    ///
+    /// // "abandon" is the only word starting with "aba"
+    /// assert_eq!(prefixes[0], 3);
+    /// ```
+    ///
+    /// [WordList::validate_structure()]: ../mnemonic/struct.WordList.html#method.validate_structure
+    pub fn unique_prefixes(&self) -> Vec<usize> {
 
-        let mut entropy_to_validate = BitVec::new();
-        &entropy_to_validate.extend((&to_validate).into_iter().take(entropy_bits));
-        assert!(entropy_to_validate.len() == entropy_bits, "invalid entropy size");
+        let words: Vec<Cow<str>> = self.words.iter().map(|word| prefix_form(word)).collect();
 
-        let entropy = entropy_to_validate.to_bytes();
+        // in sorted order, the word sharing the longest prefix with any word is always one of
+        // its neighbours
+        let mut sorted: Vec<usize> = (0..words.len()).collect();
+        sorted.sort_by(|a, b| words[*a].cmp(&words[*b]));
 
-        let hash = sha256(entropy.as_ref());
+        let common_prefix = |a: &str, b: &str| a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count();
 
-        let entropy_hash_to_validate_bits = BitVec::from_bytes(hash.as_ref());
+        let mut prefixes = vec![0; words.len()];
+        for (position, index) in sorted.iter().enumerate() {
+            let word = &words[*index];
 
+            let mut shared = 0;
+            if position > 0 {
+                shared = common_prefix(word, &words[sorted[position - 1]]);
+            }
+            if position + 1 < sorted.len() {
+                shared = shared.max(common_prefix(word, &words[sorted[position + 1]]));
+            }
 
-        let mut new_checksum = BitVec::new();
-        &new_checksum.extend(entropy_hash_to_validate_bits.into_iter().take(checksum_bits));
-        assert!(new_checksum.len() == checksum_bits, "invalid new checksum size");
-        if !(new_checksum == checksum_to_validate) {
-            return Err(ErrorKind::InvalidChecksum.into())
+            prefixes[*index] = (shared + 1).min(word.chars().count());
         }
 
-        Ok(entropy)
+        prefixes
     }
 
-    /// Get the original entropy value of the mnemonic phrase as an owned Vec<u8>
+    /// Get every word in the list that starts with `prefix`, for autocompleting user input
+    ///
+    /// The words are returned in word list order, which is alphabetical for the standard lists.
+    /// An empty prefix returns the whole list, and a prefix that matches nothing an empty `Vec`.
+    ///
+    /// Words and `prefix` are compared NFKC normalized, like in
+    /// [`Mnemonic::from_prefixes()`][Mnemonic::from_prefixes()], so composed and decomposed input
+    /// match the same words. Since every word in a standard list is identified by its first 4
+    /// characters counted that way, a prefix of 4 or more characters returns at most one word there.
     ///
     /// # Example
     ///
     /// ```
-    /// use bip39::Mnemonic;
-    /// use std::path::PathBuf;
-    /// use std::fs::File;
-    /// use std::env;
-    ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// use bip39::Language;
     ///
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
-    /// 
-    /// let word_list = Mnemonic::get_word_list(path).unwrap();
-    /// 
-    /// let mnemonic = Mnemonic::from_string(test_mnemonic, word_list, "").unwrap();
+    /// let word_list = Language::English.word_list();
     ///
-    /// let entropy: Vec<u8> = mnemonic.get_entropy();
+    /// assert_eq!(word_list.words_with_prefix("aba"), vec!["abandon"]);
+    /// assert_eq!(word_list.words_with_prefix("zo"), vec!["zone", "zoo"]);
     /// ```
     ///
-    /// Note: this function clones the internal entropy bytes
-    pub fn get_entropy(&self) -> Vec<u8> {
-        self.entropy.clone()
-    }
+    /// [Mnemonic::from_prefixes()]: ../mnemonic/struct.Mnemonic.html#method.from_prefixes
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<&str> {
 
-    /// Get the mnemonic phrase as a string reference
-    pub fn as_str(&self) -> &str {
-        self.string.as_ref()
-    }
+        let prefix = prefix_form(prefix);
 
-    /// Get the mnemonic phrase as an owned string
-    ///
-    /// Note: this clones the internal Mnemonic String instance
-    pub fn get_string(&self) -> String {
-        self.string.clone()
+        self.prefix_words()
+            .iter()
+            .zip(self.words.iter())
+            .filter(|(w, _)| w.starts_with(&*prefix))
+            .map(|(_, word)| word.as_str())
+            .collect()
     }
 
-    /// Get a reference to the internal [`Seed`][Seed]
+    /// Get the words in the list within `max_distance` edits of `word`, for "did you mean"
+    /// suggestions when a word is mistyped or misread from a written backup
     ///
-    /// [Seed]: ../seed/struct.Seed.html
-    pub fn as_seed(&self) -> &Seed {
-        &self.seed
-    }
-
-    /// Get an owned [`Seed`][Seed].
+    /// Distance is the Levenshtein distance, counting single character insertions, deletions and
+    /// substitutions. The closest words come first, ties are in word list order, and at most 10
+    /// words are returned. A word that is in the list is its own only distance 0 match.
     ///
-    /// Note: this clones the internal [`Seed`][Seed] instance
-    /// [Seed]: ../seed/struct.Seed.html
-    pub fn get_seed(&self) -> Seed {
-        self.seed.to_owned()
-    }
-
-    /// Get the original entropy used to create the Mnemonic as a hex string
+    /// # Example
     ///
-    /// Note: this allocates a new String
-    pub fn get_entropy_hex(&self) -> String {
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// assert_eq!(word_list.nearest_words("abandom", 1), vec!["abandon"]);
+    /// ```
+    pub fn nearest_words(&self, word: &str, max_distance: usize) -> Vec<&str> {
 
-        let hex = HEXUPPER.encode(self.as_entropy());
+        let mut nearest: Vec<(usize, &str)> = self.words.iter()
+            .map(|w| (levenshtein(word, w), w.as_str()))
+            .filter(|&(distance, _)| distance <= max_distance)
+            .collect();
 
-        hex
+        // stable, so words the same distance away stay in word list order
+        nearest.sort_by_key(|&(distance, _)| distance);
+
+        nearest.into_iter().take(MAX_SUGGESTIONS).map(|(_, w)| w).collect()
     }
 
-    /// Get the original entropy value of the mnemonic phrase as a slice
+    /// Find the word a user means when they only type its first few characters
     ///
-    /// # Example
+    /// An exact match always wins, otherwise `prefix` has to be at least 4 characters long and
+    /// match the start of exactly one word. The prefix and words are compared, and characters
+    /// counted, in NFKC form like in [`WordList::unique_prefixes()`][WordList::unique_prefixes()].
     ///
-    /// ```
-    /// use bip39::Mnemonic;
-    /// use std::path::PathBuf;
-    /// use std::fs::File; 
-    /// use std::env;
+    /// [WordList::unique_prefixes()]: ../mnemonic/struct.WordList.html#method.unique_prefixes
+    pub(crate) fn expand_prefix(&self, prefix: &str) -> Result<&str, Error> {
+
+        let normalized = prefix_form(prefix);
+        let prefix_words = self.prefix_words();
+
+        if let Some(index) = prefix_words.iter().position(|w| *w == normalized) {
+            return Ok(&self.words[index])
+        }
+
+        let mut matches = prefix_words.iter()
+            .enumerate()
+            .filter(|(_, w)| w.starts_with(&*normalized))
+            .map(|(index, _)| self.words[index].as_str());
+
+        match (matches.next(), matches.next()) {
+            (Some(word), None) if normalized.chars().count() >= 4 => Ok(word),
+            (Some(_), None) => Err(ErrorKind::PrefixTooShort(prefix.to_owned()).into()),
+            (None, _) => Err(ErrorKind::UnknownPrefix(prefix.to_owned()).into()),
+            _ => Err(ErrorKind::AmbiguousPrefix(prefix.to_owned()).into())
+        }
+    }
+
+    /// List the positions where this word list and `other` have different words
+    ///
+    /// Each entry is `(index, word in this list, word in other)`, in index order. Useful for
+    /// auditing a custom or updated list against an official one, where a single substituted word
+    /// is enough to break compatibility. If the lists have different lengths, the positions past
+    /// the end of the shorter list are included with an empty string for the missing word.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// assert!(word_list.diff(word_list).is_empty());
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a WordList) -> Vec<(usize, &'a str, &'a str)> {
+
+        let length = self.words.len().max(other.words.len());
+
+        (0..length).filter_map(|i| {
+            let ours = self.words.get(i).map_or("", |w| w.as_str());
+            let theirs = other.words.get(i).map_or("", |w| w.as_str());
+
+            if ours != theirs { Some((i, ours, theirs)) } else { None }
+        }).collect()
+    }
+
+    /// The words in the form prefixes are compared in, built the first time they are needed
+    pub(crate) fn prefix_words(&self) -> &[String] {
+        self.prefix_words.get_or_init(|| self.words.iter().map(|word| prefix_form(word).into_owned()).collect())
+    }
+
+    /// The word to index map, built the first time it is needed and reused for every phrase
+    /// checked against this list afterwards
+    pub(crate) fn word_map(&self) -> &WordMap {
+        self.word_map.get_or_init(|| self.gen_wordmap())
+    }
+
+    /// Build a map from each word to its index, a `HashMap` with the `std` feature and a
+    /// `BTreeMap` without it
+    pub fn gen_wordmap(&self) -> WordMap {
+
+        let mut word_map = WordMap::new();
+        for (i, item) in self.words.clone().into_iter().enumerate() {
+            word_map.insert(item.to_owned(), i as u16);
+        }
+        word_map
+    }
+}
+
+impl Mnemonic {
+
+    /// Generates a new `Mnemonic`
+    ///
+    /// Can be used to get the [`Seed`][Seed] using [`Mnemonic::get_seed()`][Mnemonic::get_seed()].
+    ///
+    /// Can also be used to get the original entropy value. Use [`Mnemonic::as_entropy()`][Mnemonic::as_entropy()] for a slice, or
+    /// [Mnemonic::get_entropy()][Mnemonic::get_entropy()] for an owned `Vec<u8>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic_type = MnemonicType::Type12Words;
+    ///
+    /// let mnemonic = match Mnemonic::new(mnemonic_type, Language::English, "") {
+    ///     Ok(b) => b,
+    ///     Err(e) => { println!("e: {}", e); return }
+    /// };
+    ///
+    /// let phrase = mnemonic.get_string();
+    /// let seed = mnemonic.get_seed();
+    /// let seed_bytes: &[u8] = seed.as_bytes();
+    /// let seed_hex: &str = seed.as_hex();
+    /// println!("phrase: {}", phrase);
+    /// ```
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Mnemonic::get_seed()]: ./mnemonic/struct.Mnemonic.html#method.get_seed
+    /// [Mnemonic::as_entropy()]: ./mnemonic/struct.Mnemonic.html#method.as_entropy
+    /// [Mnemonic::get_entropy()]: ./mnemonic/struct.Mnemonic.html#method.get_entropy
+    #[cfg(feature = "std")]
+    pub fn new<S>(mnemonic_type: MnemonicType,
+                  language: Language,
+                  password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let mut rng = OsRng::new()?;
+
+        Mnemonic::new_with_rng(mnemonic_type, language, password, &mut rng)
+    }
+
+    /// Generates a new `Mnemonic`, taking the entropy from the supplied `rng`
+    ///
+    /// This is the same as [`Mnemonic::new()`][Mnemonic::new()], which uses the operating system
+    /// RNG, but lets the caller control where the entropy comes from, for example a hardware RNG.
+    /// A seeded RNG makes generation reproducible, which is useful in tests but should never be
+    /// used for real keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::{SeedableRng, XorShiftRng};
+    ///
+    /// # fn main() {
+    /// let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    /// let first = Mnemonic::new_with_rng(MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+    ///
+    /// let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    /// let second = Mnemonic::new_with_rng(MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+    ///
+    /// assert_eq!(first.as_str(), second.as_str());
+    /// # }
+    /// ```
+    ///
+    /// [Mnemonic::new()]: ../mnemonic/struct.Mnemonic.html#method.new
+    #[cfg(feature = "std")]
+    pub fn new_with_rng<S, R>(mnemonic_type: MnemonicType,
+                              language: Language,
+                              password: S,
+                              rng: &mut R) -> Result<Mnemonic, Error> where S: Into<String>, R: Rng {
+
+        let entropy_bits = mnemonic_type.entropy_bits();
+
+        let entropy = gen_random_bytes_with(rng, entropy_bits / 8);
+
+        Mnemonic::from_entropy_owned(entropy, mnemonic_type, language, password)
+    }
+
+    /// Generates a new `Mnemonic` using the supplied `rng`, without deriving the [`Seed`][Seed] yet
+    ///
+    /// Deriving the seed runs 2048 rounds of PBKDF2, which is wasted work when the phrase is only
+    /// going to be shown to the user for backup. Here the seed is derived the first time it is
+    /// requested with [`Mnemonic::as_seed()`][Mnemonic::as_seed()] or
+    /// [`Mnemonic::get_seed()`][Mnemonic::get_seed()], so that first call pays the PBKDF2 cost
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::OsRng;
+    ///
+    /// # fn main() {
+    /// let mut rng = OsRng::new().unwrap();
+    ///
+    /// let mnemonic = Mnemonic::new_lazy(MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+    /// println!("phrase: {}", mnemonic.as_str());
+    ///
+    /// // the seed is derived here
+    /// let seed = mnemonic.get_seed();
+    /// # }
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Mnemonic::as_seed()]: ../mnemonic/struct.Mnemonic.html#method.as_seed
+    /// [Mnemonic::get_seed()]: ../mnemonic/struct.Mnemonic.html#method.get_seed
+    #[cfg(feature = "std")]
+    pub fn new_lazy<S, R>(mnemonic_type: MnemonicType,
+                          language: Language,
+                          password: S,
+                          rng: &mut R) -> Result<Mnemonic, Error> where S: Into<String>, R: Rng {
+
+        Mnemonic::new_lazy_shared(mnemonic_type, language.shared_word_list(), password.into(), rng)
+    }
+
+    /// Generates a new `Mnemonic` using the supplied `rng` and a custom word list, without
+    /// deriving the [`Seed`][Seed] yet
+    ///
+    /// Works like [`Mnemonic::new_lazy()`][Mnemonic::new_lazy()], for word lists that aren't one
+    /// of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::OsRng;
+    ///
+    /// # fn main() {
+    /// let word_list = Language::English.word_list();
+    /// let mut rng = OsRng::new().unwrap();
+    ///
+    /// let mnemonic = Mnemonic::new_lazy_with_word_list(MnemonicType::Type12Words, word_list, "", &mut rng).unwrap();
+    ///
+    /// assert_eq!(mnemonic.word_count(), 12);
+    /// # }
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Mnemonic::new_lazy()]: ../mnemonic/struct.Mnemonic.html#method.new_lazy
+    /// [Language]: ../language/enum.Language.html
+    #[cfg(feature = "std")]
+    pub fn new_lazy_with_word_list<S, R>(mnemonic_type: MnemonicType,
+                                         word_list: &WordList,
+                                         password: S,
+                                         rng: &mut R) -> Result<Mnemonic, Error> where S: Into<String>, R: Rng {
+
+        let (string, entropy) = Mnemonic::random_phrase(mnemonic_type, word_list, rng)?;
+
+        // the word list is only copied once the phrase has been built from it
+        Ok(Mnemonic::seedless(string, password.into(), Arc::new(word_list.clone()), entropy))
+    }
+
+    /// Shared by the lazy constructors that already hold a shared word list
+    #[cfg(feature = "std")]
+    pub(crate) fn new_lazy_shared<R>(mnemonic_type: MnemonicType,
+                                     word_list: Arc<WordList>,
+                                     password: String,
+                                     rng: &mut R) -> Result<Mnemonic, Error> where R: Rng {
+
+        let (string, entropy) = Mnemonic::random_phrase(mnemonic_type, &word_list, rng)?;
+
+        Ok(Mnemonic::seedless(string, password, word_list, entropy))
+    }
+
+    /// Generate random entropy from `rng` and the phrase for it
+    #[cfg(feature = "std")]
+    fn random_phrase<R>(mnemonic_type: MnemonicType,
+                        word_list: &WordList,
+                        rng: &mut R) -> Result<(String, Vec<u8>), Error> where R: Rng {
+
+        let entropy = gen_random_bytes_with(rng, mnemonic_type.entropy_bits() / 8);
+
+        let string = Mnemonic::phrase_from_entropy(&entropy, mnemonic_type, word_list)?;
+
+        Ok((string, entropy))
+    }
+
+    /// Assemble a `Mnemonic` from a phrase that is already known to be valid for `entropy`,
+    /// without deriving its seed
+    fn seedless(string: String,
+                password: String,
+                word_list: Arc<WordList>,
+                entropy: Vec<u8>) -> Mnemonic {
+
+        Mnemonic {
+            string,
+            seed: LazySeed::pending(password),
+            words: OnceLock::new(),
+            word_list,
+            entropy
+        }
+    }
+
+    /// Generate new random mnemonics until one matches `predicate`
+    ///
+    /// Entropy is taken from the supplied `rng`, and at most `max_attempts` mnemonics are generated.
+    /// If none of them match, an `Error` of kind `ErrorKind::SearchExhausted` is returned.
+    ///
+    /// Candidates are built without a [`Seed`][Seed], so an attempt costs a random draw, the
+    /// checksum and the predicate, but not the PBKDF2 derivation. Only the mnemonic that is
+    /// returned derives its seed, like any other constructor. The predicate shouldn't call
+    /// [`Mnemonic::as_seed()`][Mnemonic::as_seed()], that derives the seed for every candidate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::OsRng;
+    ///
+    /// # fn main() {
+    /// let mut rng = OsRng::new().unwrap();
+    ///
+    /// let mnemonic = Mnemonic::generate_until(MnemonicType::Type12Words, Language::English, "", &mut rng,
+    ///                                         |m| m.as_str().starts_with('a'), 1000).unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Mnemonic::as_seed()]: ../mnemonic/struct.Mnemonic.html#method.as_seed
+    #[cfg(feature = "std")]
+    pub fn generate_until<S, R, F>(mnemonic_type: MnemonicType,
+                                   language: Language,
+                                   password: S,
+                                   rng: &mut R,
+                                   predicate: F,
+                                   max_attempts: usize) -> Result<Mnemonic, Error>
+        where S: Into<String>, R: Rng, F: Fn(&Mnemonic) -> bool {
+
+        let word_list = language.shared_word_list();
+
+        Mnemonic::generate_until_shared(mnemonic_type, &word_list, Some(word_list.clone()), password.into(), rng, predicate, max_attempts)
+    }
+
+    /// Generate new random mnemonics with a custom word list until one matches `predicate`
+    ///
+    /// Works like [`Mnemonic::generate_until()`][Mnemonic::generate_until()], for word lists that
+    /// aren't one of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::OsRng;
+    ///
+    /// # fn main() {
+    /// let word_list = Language::English.word_list();
+    /// let mut rng = OsRng::new().unwrap();
+    ///
+    /// let mnemonic = Mnemonic::generate_until_with_word_list(MnemonicType::Type12Words, word_list, "", &mut rng,
+    ///                                                        |m| m.as_str().starts_with('a'), 1000).unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [Mnemonic::generate_until()]: ../mnemonic/struct.Mnemonic.html#method.generate_until
+    /// [Language]: ../language/enum.Language.html
+    #[cfg(feature = "std")]
+    pub fn generate_until_with_word_list<S, R, F>(mnemonic_type: MnemonicType,
+                                                  word_list: &WordList,
+                                                  password: S,
+                                                  rng: &mut R,
+                                                  predicate: F,
+                                                  max_attempts: usize) -> Result<Mnemonic, Error>
+        where S: Into<String>, R: Rng, F: Fn(&Mnemonic) -> bool {
+
+        Mnemonic::generate_until_shared(mnemonic_type, word_list, None, password.into(), rng, predicate, max_attempts)
+    }
+
+    /// Shared by the searching constructors, every attempt shares one copy of the word list
+    ///
+    /// `shared` is that copy if the caller already has one, otherwise `word_list` is copied once
+    /// the first phrase has been built from it.
+    #[cfg(feature = "std")]
+    fn generate_until_shared<R, F>(mnemonic_type: MnemonicType,
+                                   word_list: &WordList,
+                                   mut shared: Option<Arc<WordList>>,
+                                   password: String,
+                                   rng: &mut R,
+                                   predicate: F,
+                                   max_attempts: usize) -> Result<Mnemonic, Error>
+        where R: Rng, F: Fn(&Mnemonic) -> bool {
+
+        for _ in 0..max_attempts {
+            let (string, entropy) = Mnemonic::random_phrase(mnemonic_type, word_list, rng)?;
+
+            let shared = shared.get_or_insert_with(|| Arc::new(word_list.clone())).clone();
+            let mnemonic = Mnemonic::seedless(string, password.clone(), shared, entropy);
+
+            if predicate(&mnemonic) {
+                mnemonic.derive_default_seed();
+
+                return Ok(mnemonic)
+            }
+        }
+
+        Err(ErrorKind::SearchExhausted(max_attempts).into())
+    }
+
+    /// Generates a new `Mnemonic` from system entropy mixed with bytes supplied by the user
+    ///
+    /// `entropy_bits / 8` bytes are taken from `rng` and XORed with `user_bytes`, so the key is
+    /// only predictable to someone who can predict *both* sources. This protects against a
+    /// compromised or badly seeded system RNG, as long as the user bytes are secret and have real
+    /// entropy (dice rolls, for example), and against weak user input, as long as the RNG is sound.
+    /// It does not help if an attacker can observe both sources, or can choose `user_bytes` after
+    /// seeing the RNG output.
+    ///
+    /// If `user_bytes` isn't exactly as long as the entropy for `mnemonic_type`, it is hashed
+    /// with SHA256 and truncated to the right length first, so any amount of user input can be
+    /// mixed in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::OsRng;
+    ///
+    /// # fn main() {
+    /// let mut rng = OsRng::new().unwrap();
+    ///
+    /// let dice_rolls = b"3162445512366142536163423315";
+    /// let mnemonic = Mnemonic::new_mixed_entropy(dice_rolls, MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_mixed_entropy<S, R>(user_bytes: &[u8],
+                                   mnemonic_type: MnemonicType,
+                                   language: Language,
+                                   password: S,
+                                   rng: &mut R) -> Result<Mnemonic, Error> where S: Into<String>, R: Rng {
+
+        let entropy = Mnemonic::mixed_entropy(user_bytes, mnemonic_type, rng);
+
+        Mnemonic::from_owned_entropy_shared(entropy, mnemonic_type, language.shared_word_list(), password.into())
+    }
+
+    /// Generates a new `Mnemonic` with a custom word list from system entropy mixed with bytes
+    /// supplied by the user
+    ///
+    /// Works like [`Mnemonic::new_mixed_entropy()`][Mnemonic::new_mixed_entropy()], for word lists
+    /// that aren't one of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use rand::OsRng;
+    ///
+    /// # fn main() {
+    /// let word_list = Language::English.word_list();
+    /// let mut rng = OsRng::new().unwrap();
+    ///
+    /// let dice_rolls = b"3162445512366142536163423315";
+    /// let mnemonic = Mnemonic::new_mixed_entropy_with_word_list(dice_rolls, MnemonicType::Type12Words, word_list, "", &mut rng).unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [Mnemonic::new_mixed_entropy()]: ../mnemonic/struct.Mnemonic.html#method.new_mixed_entropy
+    /// [Language]: ../language/enum.Language.html
+    #[cfg(feature = "std")]
+    pub fn new_mixed_entropy_with_word_list<S, R>(user_bytes: &[u8],
+                                                  mnemonic_type: MnemonicType,
+                                                  word_list: &WordList,
+                                                  password: S,
+                                                  rng: &mut R) -> Result<Mnemonic, Error> where S: Into<String>, R: Rng {
+
+        let entropy = Mnemonic::mixed_entropy(user_bytes, mnemonic_type, rng);
+
+        let string = Mnemonic::checked_entropy_phrase(&entropy, mnemonic_type, word_list)?;
+
+        Ok(Mnemonic::from_parts(string, password.into(), Arc::new(word_list.clone()), entropy))
+    }
+
+    /// XOR `user_bytes`, hashed to the right length if needed, into entropy taken from `rng`
+    #[cfg(feature = "std")]
+    fn mixed_entropy<R>(user_bytes: &[u8], mnemonic_type: MnemonicType, rng: &mut R) -> Vec<u8> where R: Rng {
+
+        let entropy_bytes = mnemonic_type.entropy_bits() / 8;
+
+        let mut entropy = gen_random_bytes_with(rng, entropy_bytes);
+
+        // every supported entropy length fits in a single SHA256 hash
+        let user_entropy = if user_bytes.len() == entropy_bytes {
+            user_bytes.to_vec()
+        } else {
+            sha256(user_bytes)[..entropy_bytes].to_vec()
+        };
+
+        for (byte, user_byte) in entropy.iter_mut().zip(&user_entropy) {
+            *byte ^= *user_byte;
+        }
+
+        entropy
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from physical six sided dice rolls
+    ///
+    /// Each roll must be from 1 to 6. The rolls are read as the digits of a single base 6 number,
+    /// most significant first with 6 counting as 0, and the lowest `entropy_bits` bits of that
+    /// number become the entropy. Nothing is hashed, so the phrase can be checked by hand from
+    /// the written down rolls.
+    ///
+    /// Each roll carries about 2.58 bits, so a 12 word phrase needs at least 50 rolls and a 24
+    /// word phrase at least 100. Fewer than that returns an `Error` of kind
+    /// `ErrorKind::NotEnoughDiceRolls`, and a value outside 1 to 6 returns
+    /// `ErrorKind::InvalidDiceRoll`. Extra rolls are allowed and are all mixed in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let rolls = [3, 1, 6, 2, 4, 4, 5, 5, 1, 2, 3, 6, 6, 1, 4, 2, 5, 3, 6, 1, 6, 3, 4, 2, 3,
+    ///              3, 1, 5, 2, 4, 6, 6, 1, 3, 5, 2, 4, 1, 1, 6, 2, 5, 3, 4, 4, 6, 5, 1, 2, 3];
+    ///
+    /// let mnemonic = Mnemonic::from_dice_rolls(&rolls, MnemonicType::Type12Words, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(mnemonic.word_count(), 12);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_dice_rolls<S>(rolls: &[u8],
+                              mnemonic_type: MnemonicType,
+                              language: Language,
+                              password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let entropy_bits = mnemonic_type.entropy_bits();
+
+        // the fewest rolls whose combinations cover every possible entropy value, entropy_bits /
+        // log2(6) rounded up, in fixed point since floating point logarithms need std
+        let required = (entropy_bits * 1_000_000).div_ceil(2_584_963);
+
+        if rolls.len() < required {
+            return Err(ErrorKind::NotEnoughDiceRolls(rolls.len(), required).into())
+        }
+
+        // big endian, anything carried out of the first byte is dropped which keeps only the
+        // lowest `entropy_bits` bits
+        let mut entropy = vec![0u8; entropy_bits / 8];
+
+        for roll in rolls {
+            if *roll < 1 || *roll > 6 {
+                return Err(ErrorKind::InvalidDiceRoll(*roll).into())
+            }
+
+            let mut carry = u16::from(*roll % 6);
+            for byte in entropy.iter_mut().rev() {
+                let value = u16::from(*byte) * 6 + carry;
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+        }
+
+        Mnemonic::from_entropy_owned(entropy, mnemonic_type, language, password)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from coin flips, one entropy bit per flip
+    ///
+    /// Exactly `mnemonic_type.entropy_bits()` flips are needed, for example 128 for a 12 word
+    /// phrase, otherwise an `Error` of kind `ErrorKind::InvalidEntropyLength` is returned. The
+    /// flips are packed most significant bit first, so `true` for heads and `false` for tails
+    /// (or the other way around, as long as it's consistent) spells out the entropy directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let flips = [true; 128];
+    ///
+    /// let mnemonic = Mnemonic::from_coin_flips(&flips, MnemonicType::Type12Words, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(mnemonic.as_entropy(), &[0xFF; 16][..]);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_coin_flips<S>(flips: &[bool],
+                              mnemonic_type: MnemonicType,
+                              language: Language,
+                              password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        if flips.len() != mnemonic_type.entropy_bits() {
+            return Err(ErrorKind::InvalidEntropyLength(flips.len(), mnemonic_type).into())
+        }
+
+        let entropy = flips.chunks(8).map(|byte| {
+            byte.iter().fold(0u8, |acc, flip| (acc << 1) | u8::from(*flip))
+        }).collect::<Vec<u8>>();
+
+        Mnemonic::from_entropy_owned(entropy, mnemonic_type, language, password)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from generated entropy
+    ///
+    /// Entropy that isn't one of the standard lengths of 128, 160, 192, 224 or 256 bits returns an
+    /// `Error` of kind `ErrorKind::InvalidEntropyBits`, whatever `mnemonic_type` is. Entropy of a
+    /// standard length that doesn't match `mnemonic_type` returns `ErrorKind::InvalidEntropyLength`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    /// let mnemonic = Mnemonic::from_entropy(entropy, MnemonicType::for_key_size(128).unwrap(), Language::English, "").unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_entropy<S>(entropy: &[u8],
+                           mnemonic_type: MnemonicType,
+                           language: Language,
+                           password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        Mnemonic::from_entropy_owned(Vec::from(entropy), mnemonic_type, language, password)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from generated entropy, taking ownership of it
+    ///
+    /// Behaves exactly like [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()], but reuses the
+    /// supplied `Vec<u8>` rather than copying it when you already own the entropy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let entropy = vec![0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    /// let mnemonic = Mnemonic::from_entropy_owned(entropy, MnemonicType::for_key_size(128).unwrap(), Language::English, "").unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_owned<S>(entropy: Vec<u8>,
+                                 mnemonic_type: MnemonicType,
+                                 language: Language,
+                                 password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        Mnemonic::from_owned_entropy_shared(entropy, mnemonic_type, language.shared_word_list(), password.into())
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from generated entropy, using a custom word list
+    ///
+    /// Works like [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()], for word lists that
+    /// aren't one of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    /// let mnemonic = Mnemonic::from_entropy_with_word_list(entropy, MnemonicType::Type12Words, word_list, "").unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    /// [Language]: ../language/enum.Language.html
+    pub fn from_entropy_with_word_list<S>(entropy: &[u8],
+                                          mnemonic_type: MnemonicType,
+                                          word_list: &WordList,
+                                          password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let string = Mnemonic::checked_entropy_phrase(entropy, mnemonic_type, word_list)?;
+
+        // the word list is only copied once the entropy has been accepted
+        Ok(Mnemonic::from_parts(string, password.into(), Arc::new(word_list.clone()), Vec::from(entropy)))
+    }
+
+    /// Shared by the entropy constructors, reuses the entropy allocation and the word list
+    fn from_owned_entropy_shared(entropy: Vec<u8>,
+                                 mnemonic_type: MnemonicType,
+                                 word_list: Arc<WordList>,
+                                 password: String) -> Result<Mnemonic, Error> {
+
+        let string = Mnemonic::checked_entropy_phrase(&entropy, mnemonic_type, &word_list)?;
+
+        // the phrase was just built from the entropy, so it doesn't need to be validated again
+        Ok(Mnemonic::from_parts(string, password, word_list, entropy))
+    }
+
+    /// Build the phrase for `entropy`, checking its length first
+    fn checked_entropy_phrase(entropy: &[u8],
+                              mnemonic_type: MnemonicType,
+                              word_list: &WordList) -> Result<String, Error> {
+
+        // an entropy length no phrase can have is reported as such, rather than as a mismatch
+        // with whatever `mnemonic_type` was passed
+        MnemonicType::for_entropy_bits(entropy.len() * 8)?;
+
+        Mnemonic::phrase_from_entropy(entropy, mnemonic_type, word_list)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from generated entropy, checking the word list language first
+    ///
+    /// Works like [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()], but returns an `Error` of
+    /// kind `ErrorKind::LanguageMismatch` if the `language` of `word_list` isn't `expected_language`
+    /// (ignoring ASCII case), to catch the wrong list being passed in by mistake.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    ///
+    /// assert!(Mnemonic::from_entropy_checked(entropy, MnemonicType::Type12Words, word_list, "english", "").is_ok());
+    /// assert!(Mnemonic::from_entropy_checked(entropy, MnemonicType::Type12Words, word_list, "french", "").is_err());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_checked<S>(entropy: &[u8],
+                                   mnemonic_type: MnemonicType,
+                                   word_list: &WordList,
+                                   expected_language: &str,
+                                   password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        if !word_list.language.eq_ignore_ascii_case(expected_language) {
+            return Err(ErrorKind::LanguageMismatch(expected_language.to_owned(), word_list.language.clone()).into())
+        }
+
+        Mnemonic::from_entropy_with_word_list(entropy, mnemonic_type, word_list, password)
+    }
+
+    /// Build the phrase for some entropy without validating it or deriving a seed
+    fn phrase_from_entropy(entropy: &[u8],
+                           mnemonic_type: MnemonicType,
+                           word_list: &WordList) -> Result<String, Error> {
+
+        let indices = entropy_to_indices(entropy, mnemonic_type)?;
+
+        let separator = word_list.separator();
+
+        let mut phrase = String::new();
+        for (position, n) in indices.iter().enumerate() {
+            if position > 0 {
+                phrase.push_str(separator);
+            }
+            phrase.push_str(word_list.words.get(*n as usize).ok_or(ErrorKind::InvalidWordIndex(*n))?);
+        }
+
+        Ok(phrase)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from generated entropy hexadecimal representation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let entropy = "33E46BB13A746EA41CDDE45C90846A79";
+    /// let mnemonic = Mnemonic::from_entropy_hex(entropy, MnemonicType::for_key_size(128).unwrap(), Language::English, "").unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_entropy_hex<S>(entropy: &str,
+                           mnemonic_type: MnemonicType,
+                           language: Language,
+                           password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        Mnemonic::from_entropy_owned(HEXUPPER.decode(entropy.as_ref())?, mnemonic_type, language, password)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase
+    ///
+    /// The phrase supplied will be checked for word length and validated according to the checksum
+    /// specified in BIP0039
+    ///
+    /// The phrase and password are NFKD normalized, as BIP0039 requires, so words and passwords
+    /// typed with composed or decomposed accents give the same phrase and seed. Words are otherwise
+    /// looked up in the word list exactly as given, without changing their case.
+    ///
+    /// Leading and trailing whitespace is ignored, and words can be separated by any amount of
+    /// whitespace, including line breaks. The stored phrase, which the seed is derived from, is
+    /// NFKD normalized and has exactly one separator between words.
+    ///
+    /// The seed is derived here, which runs 2048 rounds of PBKDF2. With the `lazy-seed` feature it
+    /// is derived the first time it is requested instead, for this and every other constructor,
+    /// so phrases that are only validated or displayed never pay for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_string<S>(string: S,
+                          language: Language,
+                          password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        Mnemonic::from_string_shared(string.into(), language.shared_word_list(), password.into())
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase, borrowing the word list
+    ///
+    /// Works like [`Mnemonic::from_string()`][Mnemonic::from_string()], but the word list is only
+    /// cloned into the new [`Mnemonic`][Mnemonic] once the phrase has passed validation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
+    pub fn from_string_with_word_list<S, P>(string: S,
+                                            word_list: &WordList,
+                                            password: P) -> Result<Mnemonic, Error>
+        where S: Into<String>, P: Into<String> {
+
+        let (string, entropy) = Mnemonic::checked_phrase(string.into(), word_list)?;
+
+        Ok(Mnemonic::from_parts(string, password.into(), Arc::new(word_list.clone()), entropy))
+    }
+
+    /// Shared by the phrase constructors that already hold a shared word list
+    pub(crate) fn from_string_shared(string: String,
+                                     word_list: Arc<WordList>,
+                                     password: String) -> Result<Mnemonic, Error> {
+
+        let (string, entropy) = Mnemonic::checked_phrase(string, &word_list)?;
+
+        Ok(Mnemonic::from_parts(string, password, word_list, entropy))
+    }
+
+    /// Normalize a phrase and validate it against `word_list`, returning the phrase in the form it
+    /// is stored in along with its entropy
+    fn checked_phrase(string: String,
+                      word_list: &WordList) -> Result<(String, Vec<u8>), Error> {
+
+        // check the word count on the input first, counting doesn't allocate, so a huge phrase is
+        // rejected before it is split and copied in full
+        MnemonicType::for_word_count(split_phrase(&string).count())?;
+
+        // stray whitespace is dropped and the words are NFKD normalized like the word list, the
+        // phrase is stored and seeded in that form
+        let string = normalize_whitespace(&nfkd(&string), word_list.separator());
+
+        // this also validates the checksum and phrase length before returning the entropy so we
+        // can store it. We don't use the validate function here to avoid having a public API that
+        // takes a phrase string and returns the entropy directly. See the Mnemonic::entropy()
+        // docs for the reason.
+        let entropy = Mnemonic::entropy(&*string, word_list)?;
+
+        Ok((string, entropy))
+    }
+
+    /// Assemble a `Mnemonic` from a phrase that is already known to be valid for `entropy`
+    fn from_parts(string: String,
+                  password: String,
+                  word_list: Arc<WordList>,
+                  entropy: Vec<u8>) -> Mnemonic {
+
+        let mnemonic = Mnemonic::seedless(string, password, word_list, entropy);
+        mnemonic.derive_default_seed();
+
+        mnemonic
+    }
+
+    /// Derive the seed now, unless the `lazy-seed` feature leaves that to the first call to
+    /// `Mnemonic::as_seed()`
+    fn derive_default_seed(&self) {
+        #[cfg(not(feature = "lazy-seed"))]
+        self.as_seed();
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase pasted from JSON, logs or similar
+    ///
+    /// Works like [`Mnemonic::from_string_with_word_list()`][Mnemonic::from_string_with_word_list()],
+    /// but first strips any quotes or brackets around the phrase and its words, and accepts words
+    /// separated by commas and/or any amount of whitespace. So `"park remain …"`,
+    /// `[park, remain, …]` and `["park", "remain", …]` are all accepted. Words are also
+    /// lowercased, using the full Unicode lowercase mapping rather than just ASCII, and NFKD
+    /// normalized like the standard word lists, so `Park Remain …` is accepted too, and so is
+    /// `FIDÈLE` for a list containing `fidèle`. The phrase is then validated as usual. The stored
+    /// phrase, which the seed is derived from, is the cleaned up, lowercase version with single
+    /// separators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let pasted = "[park, remain, person, kitchen, mule, spell, knee, armed, position, rail, grid, ankle]";
+    /// let mnemonic = Mnemonic::from_string_lenient(pasted, word_list, "").unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_string_with_word_list()]: ../mnemonic/struct.Mnemonic.html#method.from_string_with_word_list
+    pub fn from_string_lenient<S, P>(string: S,
+                                     word_list: &WordList,
+                                     password: P) -> Result<Mnemonic, Error>
+        where S: Into<String>, P: Into<String> {
+
+        let m = string.into();
+
+        Mnemonic::from_string_with_word_list(Mnemonic::lenient_phrase(&m), word_list, password)
+    }
+
+    /// Strip quotes and brackets from a pasted phrase, lowercase and NFKD normalize it and rejoin
+    /// its words with single spaces
+    pub(crate) fn lenient_phrase(phrase: &str) -> String {
+
+        let is_wrapper = |c: char| c == '"' || c == '\'' || c == '`' || c == '[' || c == ']' || c == '(' || c == ')';
+
+        let words: Vec<String> = phrase.trim()
+            .trim_matches(is_wrapper)
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|word| word.trim_matches(is_wrapper))
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase().nfkd().collect())
+            .collect();
+
+        words.join(" ")
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the first few characters of each word in a phrase
+    ///
+    /// BIP39 word lists are built so that the first 4 characters of a word are enough to identify
+    /// it, once the word is NFKC normalized so that an accented letter, a kana with its voicing
+    /// mark or a Hangul syllable counts as one character. Prefixes are normalized the same way, so
+    /// they can be typed composed or decomposed. Each entry in `prefixes` must be either a complete
+    /// word, or at least its first 4 characters. Anything that doesn't match a word returns an
+    /// `Error` of kind `ErrorKind::UnknownPrefix`, and anything that could mean more than one word
+    /// returns `ErrorKind::AmbiguousPrefix`. A prefix shorter than 4 characters that only matches
+    /// one word still returns `ErrorKind::PrefixTooShort`, since in general that few characters
+    /// aren't enough. The expanded phrase is then validated as usual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+    /// let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_prefixes<S>(prefixes: &[&str],
+                            language: Language,
+                            password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let string = Mnemonic::expand_prefixes(prefixes, language.word_list())?;
+
+        Mnemonic::from_string_shared(string, language.shared_word_list(), password.into())
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the first few characters of each word in a phrase,
+    /// using a custom word list
+    ///
+    /// Works like [`Mnemonic::from_prefixes()`][Mnemonic::from_prefixes()], for word lists that
+    /// aren't one of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+    /// let mnemonic = Mnemonic::from_prefixes_with_word_list(&prefixes, word_list, "").unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_prefixes()]: ../mnemonic/struct.Mnemonic.html#method.from_prefixes
+    /// [Language]: ../language/enum.Language.html
+    pub fn from_prefixes_with_word_list<S>(prefixes: &[&str],
+                                           word_list: &WordList,
+                                           password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let string = Mnemonic::expand_prefixes(prefixes, word_list)?;
+
+        Mnemonic::from_string_with_word_list(string, word_list, password)
+    }
+
+    /// Expand each prefix to its word and join them into a phrase
+    fn expand_prefixes(prefixes: &[&str], word_list: &WordList) -> Result<String, Error> {
+
+        let mut words: Vec<&str> = Vec::with_capacity(prefixes.len());
+        for prefix in prefixes {
+            words.push(word_list.expand_prefix(prefix)?);
+        }
+
+        Ok(words.join(word_list.separator()))
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the word list index of each word in a phrase
+    ///
+    /// This is the inverse of [`Mnemonic::to_indices()`][Mnemonic::to_indices()]. The number of
+    /// indices has to be a standard phrase length, otherwise an `Error` of kind
+    /// `ErrorKind::InvalidWordCount` is returned. An index of 2048 or more returns
+    /// `ErrorKind::InvalidWordIndex`, and indices whose checksum doesn't match return
+    /// `ErrorKind::InvalidChecksum`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let indices = [415, 282, 1890, 935, 567, 656, 923, 1508, 740, 1057, 212, 1947];
+    ///
+    /// let mnemonic = Mnemonic::from_indices(&indices, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::to_indices()]: ../mnemonic/struct.Mnemonic.html#method.to_indices
+    pub fn from_indices<S>(indices: &[u16],
+                           language: Language,
+                           password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let mnemonic_type = MnemonicType::for_word_count(indices.len())?;
+
+        let entropy = indices_to_entropy(indices, mnemonic_type)?;
+
+        Mnemonic::from_entropy_owned(entropy, mnemonic_type, language, password)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase, replacing its checksum with the correct one
+    ///
+    /// For a phrase that was written down with a wrong last word. The last word carries the final
+    /// bits of entropy as well as the checksum, so only its checksum bits are recomputed from the
+    /// entropy of the phrase, which keeps it as close to what was written down as possible. A phrase
+    /// that already validates is returned unchanged.
+    ///
+    /// The rest of the phrase has to be readable: a word that isn't in the word list returns an
+    /// `Error` of kind `ErrorKind::InvalidWord`, since its entropy bits can't be known, and the
+    /// wrong number of words returns `ErrorKind::InvalidWordCount`.
+    ///
+    /// **The corrected phrase is only right if the other words are.** Any phrase can be given a
+    /// valid checksum this way, so this can't tell a mistyped last word from a mistake anywhere
+    /// else. Show the corrected phrase to the user rather than using it silently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let written_down = "park remain person kitchen mule spell knee armed position rail grid animal";
+    /// assert!(Mnemonic::validate(written_down, Language::English).is_err());
+    ///
+    /// let mnemonic = Mnemonic::regenerate_checksum(written_down, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn regenerate_checksum<S>(string: S,
+                                  language: Language,
+                                  password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let word_list = language.shared_word_list();
+
+        let (mnemonic_type, indices) = Mnemonic::phrase_indices(&string.into(), &word_list)?;
+
+        let (entropy, _) = split_indices(&indices, mnemonic_type)?;
+
+        Mnemonic::from_owned_entropy_shared(entropy, mnemonic_type, word_list, password.into())
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the abbreviated form of a phrase
+    ///
+    /// This is the inverse of [`Mnemonic::to_abbreviated()`][Mnemonic::to_abbreviated()]. The
+    /// abbreviations can be separated by any whitespace, each one is expanded like in
+    /// [`Mnemonic::from_prefixes()`][Mnemonic::from_prefixes()] and the expanded phrase is validated
+    /// as usual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_abbreviated("park rema pers kitc mule spel knee arme posi rail grid ankl", Language::English, "").unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::to_abbreviated()]: ../mnemonic/struct.Mnemonic.html#method.to_abbreviated
+    /// [Mnemonic::from_prefixes()]: ../mnemonic/struct.Mnemonic.html#method.from_prefixes
+    pub fn from_abbreviated<S>(abbreviated: &str,
+                               language: Language,
+                               password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let prefixes: Vec<&str> = abbreviated.split_whitespace().collect();
+
+        Mnemonic::from_prefixes(&prefixes, language, password)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from an abbreviated phrase, using a custom word list
+    ///
+    /// Works like [`Mnemonic::from_abbreviated()`][Mnemonic::from_abbreviated()], for word lists
+    /// that aren't one of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let mnemonic = Mnemonic::from_abbreviated_with_word_list("park rema pers kitc mule spel knee arme posi rail grid ankl", word_list, "").unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_abbreviated()]: ../mnemonic/struct.Mnemonic.html#method.from_abbreviated
+    /// [Language]: ../language/enum.Language.html
+    pub fn from_abbreviated_with_word_list<S>(abbreviated: &str,
+                                              word_list: &WordList,
+                                              password: S) -> Result<Mnemonic, Error> where S: Into<String> {
+
+        let prefixes: Vec<&str> = abbreviated.split_whitespace().collect();
+
+        Mnemonic::from_prefixes_with_word_list(&prefixes, word_list, password)
+    }
+
+    /// Validate a mnemonic phrase
+    ///
+    /// The phrase supplied will be checked for word length and validated according to the checksum
+    /// specified in BIP0039
+    ///
+    /// The first word that isn't in the word list returns an `Error` of kind
+    /// `ErrorKind::InvalidWord`, carrying the word and its position counting from 0, so a user
+    /// interface can point at it.
+    ///
+    /// Note: you cannot use this function to determine anything more than whether the mnemonic
+    /// phrase itself is intact, it does not check the password or compute the seed value. For that,
+    /// you should use [`Mnemonic::from_string()`][Mnemonic::from_string()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// match Mnemonic::validate(test_mnemonic, Language::English) {
+    ///     Ok(_) => { println!("valid: {}", test_mnemonic); },
+    ///     Err(e) => { println!("e: {}", e); return }
+    /// }
+    /// ```
+    ///
+    /// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
+    pub fn validate<S>(string: S,
+                       language: Language) -> Result<(), Error> where S: Into<String> {
+        Mnemonic::validate_with_word_list(string, language.word_list())
+    }
+
+    /// Validate a mnemonic phrase against a borrowed word list
+    ///
+    /// Works like [`Mnemonic::validate()`][Mnemonic::validate()], but lets many phrases be checked
+    /// against the same word list without cloning it for each one. To avoid copying each phrase as
+    /// well, use [`validate_phrase()`][validate_phrase()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// for phrase in &["park remain person kitchen mule spell knee armed position rail grid ankle",
+    ///                 "crop cash unable insane eight faith inflict route frame loud box vibrant"] {
+    ///     assert!(Mnemonic::validate_with_word_list(*phrase, word_list).is_ok());
+    /// }
+    /// ```
+    ///
+    /// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+    /// [validate_phrase()]: ../fn.validate_phrase.html
+    pub fn validate_with_word_list<S>(string: S,
+                                      word_list: &WordList) -> Result<(), Error> where S: Into<String> {
+        validate_phrase(&string.into(), word_list)
+    }
+
+    /// Validate a mnemonic phrase, reporting every problem found instead of just the first
+    ///
+    /// The word count, each individual word and the checksum are all checked, see
+    /// [`ValidationReport`][ValidationReport] for the details. The checksum can only be checked
+    /// once the word count and every word are valid, otherwise it is reported as
+    /// `ChecksumStatus::Unchecked`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{ChecksumStatus, Mnemonic, Language};
+    ///
+    /// let report = Mnemonic::validate_detailed("park remain persen kitchen mule spell knee armed position rail grid", Language::English);
+    ///
+    /// assert!(!report.is_ok());
+    /// assert!(report.mnemonic_type.is_none());
+    /// assert_eq!(report.invalid_words, vec![(2, "persen".to_owned())]);
+    /// assert_eq!(report.checksum, ChecksumStatus::Unchecked);
+    /// ```
+    ///
+    /// [ValidationReport]: ../validation/struct.ValidationReport.html
+    pub fn validate_detailed<S>(string: S,
+                                language: Language) -> ValidationReport where S: Into<String> {
+        Mnemonic::validate_detailed_with_word_list(string, language.word_list())
+    }
+
+    /// Validate a mnemonic phrase against a custom word list, reporting every problem found
+    ///
+    /// Works like [`Mnemonic::validate_detailed()`][Mnemonic::validate_detailed()], for word lists
+    /// that aren't one of the built in [`Language`][Language]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{ChecksumStatus, Mnemonic, Language};
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let report = Mnemonic::validate_detailed_with_word_list("park remain person kitchen mule spell knee armed position rail grid ankle", word_list);
+    ///
+    /// assert!(report.is_ok());
+    /// assert_eq!(report.checksum, ChecksumStatus::Valid);
+    /// ```
+    ///
+    /// [Mnemonic::validate_detailed()]: ../mnemonic/struct.Mnemonic.html#method.validate_detailed
+    /// [Language]: ../language/enum.Language.html
+    pub fn validate_detailed_with_word_list<S>(string: S,
+                                               word_list: &WordList) -> ValidationReport where S: Into<String> {
+        let m = string.into();
+
+        let mut indices: Vec<u16> = Vec::new();
+        let mut invalid_words: Vec<(usize, String)> = Vec::new();
+
+        for (position, word) in split_phrase(&m).enumerate() {
+            match word_list.position(&nfkd(word)) {
+                Some(n) => indices.push(n),
+                None => invalid_words.push((position, word.to_owned()))
+            }
+        }
+
+        let word_count = indices.len() + invalid_words.len();
+        let mnemonic_type = MnemonicType::for_word_count(word_count).ok();
+
+        let checksum = match mnemonic_type {
+            Some(mnemonic_type) if invalid_words.is_empty() => {
+                if checksum_matches(&indices, mnemonic_type) {
+                    ChecksumStatus::Valid
+                } else {
+                    ChecksumStatus::Invalid
+                }
+            },
+            _ => ChecksumStatus::Unchecked
+        };
+
+        ValidationReport {
+            word_count,
+            mnemonic_type,
+            invalid_words,
+            checksum,
+        }
+    }
+
+    /// Validate a mnemonic phrase in several languages, returning the one it belongs to
+    ///
+    /// Useful when the language of a phrase isn't known. Each language is tried in order, and the
+    /// first one the phrase fully validates in (words and checksum) is returned. If none of them
+    /// match, an `Error` of kind `ErrorKind::NoMatchingLanguage` is returned, listing why the
+    /// phrase failed for each language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let matched = Mnemonic::validate_any(test_mnemonic, &[Language::Japanese, Language::English]).unwrap();
+    ///
+    /// assert_eq!(matched, Language::English);
+    /// ```
+    pub fn validate_any<S>(string: S,
+                           languages: &[Language]) -> Result<Language, Error> where S: Into<String> {
+        let m = string.into();
+
+        let mut failures: Vec<String> = Vec::with_capacity(languages.len());
+
+        for language in languages {
+            let word_list = language.word_list();
+
+            match Mnemonic::entropy(&*m, word_list) {
+                Ok(_) => return Ok(*language),
+                Err(e) => failures.push(format!("{}: {}", word_list.language, e))
+            }
+        }
+
+        Err(ErrorKind::NoMatchingLanguage(failures).into())
+    }
+
+    /// Validate a mnemonic phrase against several word lists, returning the one it belongs to
+    ///
+    /// Works like [`Mnemonic::validate_any()`][Mnemonic::validate_any()], for custom word lists
+    /// that don't have a [`Language`][Language].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let matched = Mnemonic::validate_any_with_word_lists(test_mnemonic, &[word_list]).unwrap();
+    ///
+    /// assert_eq!(matched.as_language(), Some(Language::English));
+    /// ```
+    ///
+    /// [Mnemonic::validate_any()]: ../mnemonic/struct.Mnemonic.html#method.validate_any
+    /// [Language]: ../language/enum.Language.html
+    pub fn validate_any_with_word_lists<'a, S>(string: S,
+                                               word_lists: &[&'a WordList]) -> Result<&'a WordList, Error>
+        where S: Into<String> {
+        let m = string.into();
+
+        let mut failures: Vec<String> = Vec::with_capacity(word_lists.len());
+
+        for word_list in word_lists {
+            match Mnemonic::entropy(&*m, word_list) {
+                Ok(_) => return Ok(word_list),
+                Err(e) => failures.push(format!("{}: {}", word_list.language, e))
+            }
+        }
+
+        Err(ErrorKind::NoMatchingLanguage(failures).into())
+    }
+
+    /// Check whether a set of word indices has a valid checksum
+    ///
+    /// This is the same check [`indices_to_entropy()`][indices_to_entropy()] does, without
+    /// building a [`Mnemonic`][Mnemonic], touching any strings or returning the entropy, for
+    /// recovery tools that need to test a large number of candidate index sets quickly. Returns
+    /// `false` if the number of indices doesn't match `mnemonic_type`, or any index is outside the
+    /// 2048 word range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{entropy_to_indices, Mnemonic, MnemonicType};
+    ///
+    /// let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    ///
+    /// let indices = entropy_to_indices(entropy, MnemonicType::Type12Words).unwrap();
+    ///
+    /// assert!(Mnemonic::checksum_matches(&indices, MnemonicType::Type12Words));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [indices_to_entropy()]: ../fn.indices_to_entropy.html
+    pub fn checksum_matches(indices: &[u16],
+                            mnemonic_type: MnemonicType) -> bool {
+        checksum_matches(indices, mnemonic_type)
+    }
+
+    /// Get every word that completes `partial` to a phrase with a valid checksum
+    ///
+    /// For recovering a phrase when only the last word is lost, or for picking the last word when
+    /// the rest were generated by hand. `partial` has to be one word short of a standard phrase
+    /// length, so 11, 14, 17, 20 or 23 words, otherwise an `Error` of kind
+    /// `ErrorKind::InvalidWordCount` is returned with the length the completed phrase would have.
+    /// A word that isn't in the list for `language` returns `ErrorKind::InvalidWord` with the word
+    /// and its position.
+    ///
+    /// The last word carries part of the entropy as well as the checksum, so there are always
+    /// several candidates, 128 for a 12 word phrase down to 8 for a 24 word one. They are
+    /// returned in word list order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let partial = ["park", "remain", "person", "kitchen", "mule", "spell", "knee", "armed", "position", "rail", "grid"];
+    ///
+    /// let last_words = Mnemonic::valid_last_words(&partial, Language::English).unwrap();
+    ///
+    /// assert_eq!(last_words.len(), 128);
+    /// assert!(last_words.contains(&"ankle"));
+    /// ```
+    pub fn valid_last_words(partial: &[&str],
+                            language: Language) -> Result<Vec<&'static str>, Error> {
+
+        let last_words = Mnemonic::last_word_candidates(partial, language)?;
+
+        Ok(last_words.into_iter().map(|(word, _)| word).collect())
+    }
+
+    /// Get every word that completes `partial` to a valid phrase, with the entropy each one gives
+    ///
+    /// Works like [`Mnemonic::valid_last_words()`][Mnemonic::valid_last_words()], with the same
+    /// errors and in the same order, but also returns the entropy of the phrase completed by each
+    /// word. A recovery tool can derive an account from each of them and let the user pick the one
+    /// they recognize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let partial = ["park", "remain", "person", "kitchen", "mule", "spell", "knee", "armed", "position", "rail", "grid"];
+    ///
+    /// let candidates = Mnemonic::generate_all_valid_last_words(&partial, Language::English).unwrap();
+    ///
+    /// for (word, entropy) in candidates {
+    ///     let phrase = format!("{} {}", partial.join(" "), word);
+    ///     let mnemonic = Mnemonic::from_entropy(&entropy, MnemonicType::Type12Words, Language::English, "").unwrap();
+    ///
+    ///     assert_eq!(phrase, mnemonic.as_str());
+    /// }
+    /// ```
+    ///
+    /// [Mnemonic::valid_last_words()]: ../mnemonic/struct.Mnemonic.html#method.valid_last_words
+    pub fn generate_all_valid_last_words(partial: &[&str],
+                                         language: Language) -> Result<Vec<(String, Vec<u8>)>, Error> {
+
+        let last_words = Mnemonic::last_word_candidates(partial, language)?;
+
+        Ok(last_words.into_iter().map(|(word, entropy)| (word.to_owned(), entropy)).collect())
+    }
+
+    /// Every last word with a valid checksum for `partial`, and the entropy of the finished phrase
+    fn last_word_candidates(partial: &[&str],
+                            language: Language) -> Result<Vec<(&'static str, Vec<u8>)>, Error> {
+
+        let mnemonic_type = MnemonicType::for_word_count(partial.len() + 1)?;
+
+        let word_list = language.word_list();
+        let word_map = word_list.word_map();
+
+        let mut indices: Vec<u16> = Vec::with_capacity(mnemonic_type.word_count());
+
+        for (index, word) in partial.iter().enumerate() {
+            match word_map.get(*word) {
+                Some(n) => indices.push(*n),
+                None => return Err(ErrorKind::InvalidWord((*word).to_owned(), index).into())
+            };
+        }
+
+        indices.push(0);
+
+        let mut last_words = Vec::new();
+
+        for (n, word) in word_list.words.iter().enumerate() {
+            indices[partial.len()] = n as u16;
+
+            if checksum_matches(&indices, mnemonic_type) {
+                let (entropy, _) = split_indices(&indices, mnemonic_type)?;
+                last_words.push((word.as_str(), entropy));
+            }
+        }
+
+        Ok(last_words)
+    }
+
+    /// Calculate the checksum, verify it and return the entropy
+    ///
+    /// Only intended for internal use, as returning a `Vec<u8>` that looks a bit like it could be
+    /// used as the seed is likely to cause problems for someone eventually. All the other functions
+    /// that return something like that are explicit about what it is and what to use it for.
+    fn entropy<S>(string: S,
+                  word_list: &WordList) -> Result<Vec<u8>, Error> where S: Into<String> {
+
+        let (mnemonic_type, indices) = Mnemonic::phrase_indices(&string.into(), word_list)?;
+
+        indices_to_entropy(&indices, mnemonic_type)
+    }
+
+    /// Look up the word list index of every word in a phrase, along with the phrase's `MnemonicType`
+    pub(crate) fn phrase_indices(m: &str,
+                      word_list: &WordList) -> Result<(MnemonicType, Vec<u16>), Error> {
+
+        let mnemonic_type = MnemonicType::for_word_count(split_phrase(m).count())?;
+
+        // the word list is NFKD, so composed input like an accented letter typed as one character
+        // has to be decomposed to be found
+        let m = nfkd(m);
+
+        // reject phrases with words that are definitely not in the list before paying for the
+        // word map
+        if let Some((index, word)) = split_phrase(&m).enumerate().find(|(_, word)| !word_list.maybe_contains(word)) {
+            return Err(ErrorKind::InvalidWord(word.to_owned(), index).into())
+        }
+
+        let word_map = word_list.word_map();
+
+        let mut indices: Vec<u16> = Vec::with_capacity(mnemonic_type.word_count());
+
+        for (index, word) in split_phrase(&m).enumerate() {
+            match word_map.get(word) {
+                Some(n) => indices.push(*n),
+                None => return Err(ErrorKind::InvalidWord(word.to_owned(), index).into())
+            };
+        }
+
+        Ok((mnemonic_type, indices))
+    }
+
+    /// Split a 24 word phrase into two 12 word phrases, if both halves happen to be valid
+    ///
+    /// A 24 word phrase can't generally be stored as two independent 12 word phrases: each half
+    /// would need its own checksum, and the words that would carry it are just entropy in the full
+    /// phrase. Each half only has a 1 in 16 chance of validating by accident, so this returns
+    /// `None` for roughly 255 out of every 256 phrases, and always for phrases that aren't 24 words
+    /// long. When it does return the two halves, they use the same word list as this
+    /// [`Mnemonic`][Mnemonic] and derive their seeds with `password`, which isn't kept once this
+    /// one's seed has been derived.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "always guess retreat devote warm poem giraffe thought prize ready maple \
+    ///                      daughter girl feel clay silent lemon bracket abstract basket toe tiny \
+    ///                      sword world";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert!(mnemonic.try_split_12_12("").is_none());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn try_split_12_12(&self, password: &str) -> Option<(Mnemonic, Mnemonic)> {
+
+        let words: Vec<&str> = split_phrase(&self.string).collect();
+
+        if words.len() != 24 {
+            return None
+        }
+
+        let separator = self.word_list.separator();
+        let first = Mnemonic::from_string_shared(words[..12].join(separator), self.word_list.clone(), password.to_owned()).ok()?;
+        let second = Mnemonic::from_string_shared(words[12..].join(separator), self.word_list.clone(), password.to_owned()).ok()?;
+
+        Some((first, second))
+    }
+
+    /// Rebuild the phrase from the stored entropy and word list
+    ///
+    /// The returned [`Mnemonic`][Mnemonic] has the canonical form of the phrase, words joined with
+    /// the separator of the word list's language (a single space, or U+3000 for Japanese) and
+    /// spelled exactly as they are in the word list, whatever input this one was created from.
+    /// Useful for storing a clean version of a phrase that was typed in by hand.
+    ///
+    /// The seed carries over as it is: a seed that is still pending is derived from the canonical
+    /// phrase with the same password, one that has already been derived is copied, since the
+    /// password is no longer kept. Phrases are stored in canonical form when they are parsed, so
+    /// the two only differ for a corrupted instance, which
+    /// [`Mnemonic::try_clone()`][Mnemonic::try_clone()] detects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+    /// let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English, "").unwrap();
+    ///
+    /// let canonical = mnemonic.rebuild_canonical();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", canonical.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::try_clone()]: ../mnemonic/struct.Mnemonic.html#method.try_clone
+    pub fn rebuild_canonical(&self) -> Mnemonic {
+
+        Mnemonic {
+            string: self.canonical_phrase().expect("stored entropy has an invalid length"),
+            seed: self.seed.clone(),
+            words: OnceLock::new(),
+            word_list: self.word_list.clone(),
+            entropy: self.entropy.clone(),
+        }
+    }
+
+    /// Rewrite the stored phrase in its canonical form, in place
+    ///
+    /// Works like [`Mnemonic::rebuild_canonical()`][Mnemonic::rebuild_canonical()] without creating
+    /// a new instance. The seed is kept as it is, pending or derived, so normalizing a phrase
+    /// more than once has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+    /// let mut mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English, "").unwrap();
+    ///
+    /// mnemonic.normalize_phrase();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.as_str());
+    /// ```
+    ///
+    /// [Mnemonic::rebuild_canonical()]: ../mnemonic/struct.Mnemonic.html#method.rebuild_canonical
+    pub fn normalize_phrase(&mut self) {
+
+        let string = self.canonical_phrase().expect("stored entropy has an invalid length");
+
+        if string != self.string {
+            self.string = string;
+            self.words = OnceLock::new();
+        }
+    }
+
+    /// Build the canonical phrase for the stored entropy
+    ///
+    /// Stored entropy that no phrase can have is only possible if this instance was corrupted,
+    /// that returns an `Error` of kind `ErrorKind::CorruptedMnemonic`.
+    fn canonical_phrase(&self) -> Result<String, Error> {
+
+        MnemonicType::for_key_size(self.entropy.len() * 8)
+            .and_then(|mnemonic_type| Mnemonic::phrase_from_entropy(&self.entropy, mnemonic_type, &self.word_list))
+            .map_err(|_| ErrorKind::CorruptedMnemonic.into())
+    }
+
+    /// Encode the entropy of this [`Mnemonic`][Mnemonic] with the word list of another language
+    ///
+    /// The new phrase is built from the same entropy, so it represents the same key material, but
+    /// BIP39 derives the seed from the phrase itself rather than the entropy. The returned
+    /// `Mnemonic` therefore has a different [`Seed`][Seed] than this one, the standard seed for the
+    /// phrase in `target` with `password`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let japanese = mnemonic.to_language(Language::Japanese, "").unwrap();
+    ///
+    /// assert!(japanese.same_entropy(&mnemonic));
+    /// assert_eq!(japanese.language(), Some(Language::Japanese));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn to_language(&self, target: Language, password: &str) -> Result<Mnemonic, Error> {
+
+        Mnemonic::from_entropy(&self.entropy, self.mnemonic_type(), target, password)
+    }
+
+    /// Clone this [`Mnemonic`][Mnemonic], checking that it is still intact first
+    ///
+    /// The derived `Clone` copies the fields as they are. This instead rebuilds the phrase from the
+    /// stored entropy and validates the stored phrase again, checksum included, so a corrupted
+    /// instance can't be copied around silently. If the phrase doesn't validate, the entropy has a
+    /// length no phrase can have, or the two no longer match, an `Error` of kind
+    /// `ErrorKind::CorruptedMnemonic` is returned. It never panics, whatever state it finds.
+    ///
+    /// If the [`Seed`][Seed] is still pending, the clone derives it from the verified phrase the
+    /// first time it is requested. If it has already been derived it is copied, the password it
+    /// would take to derive it again isn't kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let copy = mnemonic.try_clone().unwrap();
+    ///
+    /// assert_eq!(mnemonic.as_str(), copy.as_str());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn try_clone(&self) -> Result<Mnemonic, Error> {
+
+        let entropy = Mnemonic::entropy(&*self.string, &self.word_list)
+            .map_err(|_| Error::from(ErrorKind::CorruptedMnemonic))?;
+
+        if entropy != self.entropy || self.canonical_phrase()? != self.string {
+            return Err(ErrorKind::CorruptedMnemonic.into())
+        }
+
+        let mnemonic = Mnemonic {
+            string: self.string.clone(),
+            seed: self.seed.clone(),
+            words: OnceLock::new(),
+            word_list: self.word_list.clone(),
+            entropy
+        };
+
+        Ok(mnemonic)
+    }
+
+    /// Get a short user facing description of the phrase strength, like `"Standard (128-bit)"`
+    ///
+    /// See [`MnemonicType::strength_label()`][MnemonicType::strength_label()] for the full list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("Standard (128-bit)", mnemonic.strength_label());
+    /// ```
+    ///
+    /// [MnemonicType::strength_label()]: ../mnemonic_type/enum.MnemonicType.html#method.strength_label
+    pub fn strength_label(&self) -> &'static str {
+
+        self.mnemonic_type().strength_label()
+    }
+
+    /// Get the number of words in the phrase
+    ///
+    /// This comes from the length of the stored entropy, the phrase isn't split again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(12, mnemonic.word_count());
+    /// ```
+    pub fn word_count(&self) -> usize {
+
+        self.mnemonic_type().word_count()
+    }
+
+    /// Get the number of entropy bits in the phrase, the strength of the keys derived from it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(128, mnemonic.entropy_bits());
+    /// ```
+    pub fn entropy_bits(&self) -> usize {
+
+        self.mnemonic_type().entropy_bits()
+    }
+
+    /// Get the number of checksum bits at the end of the phrase, one for every 32 bits of entropy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(4, mnemonic.checksum_bits());
+    /// ```
+    pub fn checksum_bits(&self) -> usize {
+
+        self.mnemonic_type().checksum_bits()
+    }
+
+    /// Get the checksum carried by the last word of the phrase
+    ///
+    /// The checksum is the first [`Mnemonic::checksum_bits()`][Mnemonic::checksum_bits()] bits of
+    /// the SHA256 hash of the entropy, from 4 bits for a 12 word phrase to 8 bits for a 24 word one.
+    /// They are right aligned in the returned byte, so the value is the checksum as a number and
+    /// matches the low bits of the last word's index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// // "vibrant" is word 1947, 0b1111001_1011
+    /// assert_eq!(0b1011, mnemonic.checksum());
+    /// ```
+    ///
+    /// [Mnemonic::checksum_bits()]: ../mnemonic/struct.Mnemonic.html#method.checksum_bits
+    pub fn checksum(&self) -> u8 {
+
+        checksum(&self.entropy, self.mnemonic_type())
+    }
+
+    /// Get the [`Language`][Language] of the phrase
+    ///
+    /// Returns `None` for phrases built from a custom word list, see
+    /// [`WordList::as_language()`][WordList::as_language()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(Some(Language::English), mnemonic.language());
+    /// ```
+    ///
+    /// [Language]: ../language/enum.Language.html
+    /// [WordList::as_language()]: ../mnemonic/struct.WordList.html#method.as_language
+    pub fn language(&self) -> Option<Language> {
+
+        self.word_list.as_language()
+    }
+
+    /// The `MnemonicType` matching the stored entropy, which was checked when it was created
+    fn mnemonic_type(&self) -> MnemonicType {
+
+        MnemonicType::for_key_size(self.entropy.len() * 8)
+            .expect("stored entropy has an invalid length")
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as an owned Vec<u8>
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let entropy: Vec<u8> = mnemonic.get_entropy();
+    /// ```
+    ///
+    /// Note: this function clones the internal entropy bytes
+    pub fn get_entropy(&self) -> Vec<u8> {
+        self.entropy.clone()
+    }
+
+    /// Get the mnemonic phrase as a string reference
+    pub fn as_str(&self) -> &str {
+        self.string.as_ref()
+    }
+
+    /// Get the mnemonic phrase as an owned string
+    ///
+    /// Note: this clones the internal Mnemonic String instance
+    pub fn get_string(&self) -> String {
+        self.string.clone()
+    }
+
+    /// Get the phrase with every word shortened to its first 4 characters
+    ///
+    /// BIP39 word lists are built so that the first 4 characters identify each word, so this is a
+    /// compact but complete form of the phrase. Words of 4 characters or less are kept whole. It
+    /// can be turned back into a [`Mnemonic`][Mnemonic] with
+    /// [`Mnemonic::from_abbreviated()`][Mnemonic::from_abbreviated()].
+    ///
+    /// Characters are counted with each word NFKC normalized, the same way
+    /// [`Mnemonic::from_prefixes()`][Mnemonic::from_prefixes()] counts them, so an accented letter,
+    /// a kana with its voicing mark or a Hangul syllable is one character. The abbreviations are
+    /// NFKD normalized like the phrase itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
     ///
     /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
     ///
-    /// let mut path = PathBuf::from(env::current_dir().unwrap());
-    /// path.push("src/english.json");
-    /// 
-    /// let word_list = Mnemonic::get_word_list(path).unwrap();
-    /// 
-    /// let mnemonic = Mnemonic::from_string(test_mnemonic, word_list, "").unwrap();
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("park rema pers kitc mule spel knee arme posi rail grid ankl", mnemonic.to_abbreviated());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_abbreviated()]: ../mnemonic/struct.Mnemonic.html#method.from_abbreviated
+    /// [Mnemonic::from_prefixes()]: ../mnemonic/struct.Mnemonic.html#method.from_prefixes
+    pub fn to_abbreviated(&self) -> String {
+
+        let abbreviations: Vec<String> = split_phrase(&self.string).map(|word| {
+            let prefix: String = prefix_form(word).chars().take(4).collect();
+            nfkd(&prefix).into_owned()
+        }).collect();
+
+        abbreviations.join(self.word_list.separator())
+    }
+
+    /// Get the words of the phrase as a slice
+    ///
+    /// The phrase is split the first time this is called and the result is kept, so repeated
+    /// access to individual words (in a UI render loop, for example) doesn't split it every time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("ankle", mnemonic.words_as_slice()[11]);
+    /// ```
+    pub fn words_as_slice(&self) -> &[String] {
+        self.words.get_or_init(|| split_phrase(&self.string).map(|word| word.to_owned()).collect())
+    }
+
+    /// Iterate over the words of the phrase
+    ///
+    /// Words are split on the ASCII space, or the ideographic space Japanese phrases are joined
+    /// with, so this always yields one item per word without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// for (n, word) in mnemonic.words().enumerate() {
+    ///     println!("{:>2}. {}", n + 1, word);
+    /// }
+    ///
+    /// assert_eq!(mnemonic.words().last(), Some("ankle"));
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        split_phrase(&self.string)
+    }
+
+    /// Iterate over the words of the phrase together with their position and word list index
+    ///
+    /// Yields `(position, index, word)` for each word, in phrase order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// for (position, index, word) in mnemonic.iter_with_indices() {
+    ///     println!("{}: {} ({})", position + 1, word, index);
+    /// }
+    /// ```
+    pub fn iter_with_indices(&self) -> impl Iterator<Item = (usize, u16, &str)> {
+        split_phrase(&self.string).enumerate().map(move |(position, word)| {
+            let index = self.word_list.position(word).expect("mnemonic word missing from its word list");
+            (position, index, word)
+        })
+    }
+
+    /// Get the word list index of each word in the phrase, in phrase order
+    ///
+    /// Each index is below 2048, the representation hardware wallets and other tools use when they
+    /// deal in numbers rather than words. [`Mnemonic::from_indices()`][Mnemonic::from_indices()]
+    /// turns them back into a `Mnemonic`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(mnemonic.to_indices(), vec![415, 282, 1890, 935, 567, 656, 923, 1508, 740, 1057, 212, 1947]);
+    /// ```
+    ///
+    /// [Mnemonic::from_indices()]: ../mnemonic/struct.Mnemonic.html#method.from_indices
+    pub fn to_indices(&self) -> Vec<u16> {
+        owned_entropy_to_indices(self.entropy.clone(), self.mnemonic_type())
+            .expect("stored entropy has an invalid length")
+    }
+
+    /// Get a reference to the internal [`Seed`][Seed]
+    ///
+    /// If the seed hasn't been derived yet, it is derived now. The password is only kept until
+    /// then, it is dropped as soon as the seed exists, and cleared first with the `zeroize`
+    /// feature.
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn as_seed(&self) -> &Seed {
+        self.seed.get_or_derive(&self.string)
+    }
+
+    /// Get an owned [`Seed`][Seed].
+    ///
+    /// Note: this clones the internal [`Seed`][Seed] instance
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn get_seed(&self) -> Seed {
+        self.as_seed().to_owned()
+    }
+
+    /// Get the original entropy used to create the Mnemonic as a hex string
+    ///
+    /// Note: this allocates a new String
+    pub fn get_entropy_hex(&self) -> String {
+
+        let hex = HEXUPPER.encode(self.as_entropy());
+
+        hex
+    }
+
+    /// Get the original entropy used to create the Mnemonic as a lowercase hex string
+    ///
+    /// Note: this allocates a new String
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert_eq!(mnemonic.get_entropy_hex_lower(), "33e46bb13a746ea41cdde45c90846a79");
+    /// ```
+    pub fn get_entropy_hex_lower(&self) -> String {
+
+        HEXLOWER.encode(self.as_entropy())
+    }
+
+    /// Get the first `visible` characters of the entropy hex string, followed by `…`
+    ///
+    /// Useful when a partial value is needed to identify a wallet, for example in a support
+    /// request, without exposing the whole entropy. If `visible` covers the whole hex string it is
+    /// returned unmasked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let entropy = "33E46BB13A746EA41CDDE45C90846A79";
+    /// let mnemonic = Mnemonic::from_entropy_hex(entropy, MnemonicType::Type12Words, Language::English, "").unwrap();
+    ///
+    /// assert_eq!("33E46B…", mnemonic.masked_entropy_hex(6));
+    /// ```
+    ///
+    /// Note: this allocates a new String
+    pub fn masked_entropy_hex(&self, visible: usize) -> String {
+
+        let mut hex = self.get_entropy_hex();
+
+        if visible < hex.len() {
+            hex.truncate(visible);
+            hex.push('…');
+        }
+
+        hex
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as a slice
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
     ///
     /// let entropy: &[u8] = mnemonic.as_entropy();
     /// ```
@@ -436,30 +2623,103 @@ impl Mnemonic {
         self.entropy.as_ref()
     }
 
+    /// Check whether this and `other` encode the same entropy
+    ///
+    /// This is true for the same phrase written with word lists for different languages, but
+    /// those phrases still have different seeds, see [`Seed`][Seed]. Use this to check that two
+    /// phrases are the same backup, not that they open the same wallet. The entropy is compared in
+    /// constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let word_list = Language::English.word_list();
+    ///
+    /// let first = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+    /// let second = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+    ///
+    /// assert!(first.same_entropy(&second));
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn same_entropy(&self, other: &Mnemonic) -> bool {
+        constant_time_eq(&self.entropy, &other.entropy)
+    }
+
+    /// Check whether this is a well known, publicly published phrase
+    ///
+    /// Returns `true` for the official BIP39 test vectors, common development defaults like
+    /// `test test test test test test test test test test test junk`, and the examples in this
+    /// crate's documentation. Anyone can take funds sent to these, so wallets should warn the user
+    /// not to use them. The check is done on the entropy, so it works for the same phrases in any
+    /// language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// assert!(mnemonic.is_known_test_vector());
+    /// ```
+    pub fn is_known_test_vector(&self) -> bool {
+
+        let hex = self.get_entropy_hex();
+
+        KNOWN_TEST_ENTROPY.iter().any(|known| known.eq_ignore_ascii_case(&hex))
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as a fixed size array
+    ///
+    /// Returns `None` unless the entropy is exactly `N` bytes long, so for example a 12 word
+    /// phrase can only be read into a `[u8; 16]` and a 24 word phrase into a `[u8; 32]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let entropy: [u8; 16] = mnemonic.entropy_array().unwrap();
+    /// ```
+    pub fn entropy_array<const N: usize>(&self) -> Option<[u8; N]> {
+        <[u8; N]>::try_from(self.as_entropy()).ok()
+    }
+
     /// Get the word list given a directory path
-    /// 
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidFile` if the file can't be opened, otherwise
+    /// this works like [`WordList::from_reader()`][WordList::from_reader()].
+    ///
     /// # Example
     /// 
     /// ```
     /// use bip39::Mnemonic;
     /// use std::path::PathBuf;
     /// use std::env;
-    /// 
+    ///
     /// let mut path = PathBuf::from(env::current_dir().unwrap());
     /// path.push("src/english.json");
-    /// 
+    ///
     /// let word_list = Mnemonic::get_word_list(path).unwrap();
     /// ```
-    /// 
+    ///
+    /// [WordList::from_reader()]: ../mnemonic/struct.WordList.html#method.from_reader
+    #[cfg(feature = "std")]
     pub fn get_word_list(path: PathBuf) -> Result<WordList, Error> {
-        let file = File::open(path).unwrap();
-        let word_list: WordList;
+        let file = File::open(path).map_err(|_| Error::from(ErrorKind::InvalidFile))?;
 
-        match de::from_reader(file) {
-            Ok(w) => word_list = w,
-            Err(_) => return Err(ErrorKind::InvalidFile.into())
-        }
-        Ok(word_list)
+        WordList::from_reader(file)
     }
 }
 
@@ -468,3 +2728,158 @@ impl AsRef<str> for Mnemonic {
         self.as_str()
     }
 }
+
+/// Two mnemonics are equal when they have the same entropy and word list
+///
+/// Word lists are compared by their name and words, so two custom lists that share a name but
+/// not their words give different phrases and are not equal. The phrase string isn't compared,
+/// so the same backup parsed from differently formatted input is equal. The entropy is compared
+/// in constant time, like [`Seed::ct_eq()`][Seed::ct_eq()]. **The password, and so the seed, is
+/// ignored**: equal mnemonics with different passwords open different wallets. Use
+/// [`Mnemonic::same_entropy()`][Mnemonic::same_entropy()] to also ignore the language.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Language, Mnemonic};
+///
+/// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+///
+/// let first = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+/// let second = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+///
+/// assert!(first == second);
+/// assert_ne!(first.get_seed().as_bytes(), second.get_seed().as_bytes());
+/// ```
+///
+/// [Mnemonic::same_entropy()]: ../mnemonic/struct.Mnemonic.html#method.same_entropy
+/// [Seed::ct_eq()]: ../seed/struct.Seed.html#method.ct_eq
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Mnemonic) -> bool {
+        self.same_entropy(other) && (Arc::ptr_eq(&self.word_list, &other.word_list) ||
+            (self.word_list.language == other.word_list.language && self.word_list.words == other.word_list.words))
+    }
+}
+
+impl Eq for Mnemonic {}
+
+/// Writes the phrase, exactly as returned by [`Mnemonic::as_str()`][Mnemonic::as_str()]
+///
+/// [Mnemonic::as_str()]: ../mnemonic/struct.Mnemonic.html#method.as_str
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses an English phrase with an empty password
+///
+/// Use [`Mnemonic::from_string()`][Mnemonic::from_string()] for other languages or a password.
+///
+/// # Example
+///
+/// ```
+/// use bip39::Mnemonic;
+///
+/// let mnemonic: Mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle".parse().unwrap();
+///
+/// assert_eq!(12, mnemonic.word_count());
+/// assert!("park remain person".parse::<Mnemonic>().is_err());
+/// ```
+///
+/// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Mnemonic, Error> {
+        Mnemonic::from_string(s, Language::English, "")
+    }
+}
+
+/// How a [`Mnemonic`][Mnemonic] is stored by `serde`, the phrase and the word list language
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+#[cfg(feature = "serialize")]
+#[derive(Serialize, Deserialize)]
+struct SerializedMnemonic<'a> {
+    phrase: Cow<'a, str>,
+    language: Cow<'a, str>,
+}
+
+/// Stores the phrase and word list language, with the `serialize` feature
+///
+/// The entropy, password and seed are not stored. The seed depends on the password, which
+/// shouldn't be written out next to the phrase, so a deserialized `Mnemonic` always has an empty
+/// password and the seed is derived from that.
+///
+/// # Example
+///
+/// ```
+/// # extern crate bip39;
+/// # extern crate serde_json;
+/// use bip39::{Language, Mnemonic};
+///
+/// # fn main() {
+/// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+///
+/// let json = serde_json::to_string(&mnemonic).unwrap();
+///
+/// assert_eq!(json, format!(r#"{{"phrase":"{}","language":"english"}}"#, test_mnemonic));
+/// # }
+/// ```
+#[cfg(feature = "serialize")]
+impl Serialize for Mnemonic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        SerializedMnemonic {
+            phrase: Cow::Borrowed(self.as_str()),
+            language: Cow::Borrowed(&self.word_list.language),
+        }.serialize(serializer)
+    }
+}
+
+/// Reads a phrase and language written by `Serialize`, validating the phrase again
+///
+/// The phrase is parsed with [`Mnemonic::from_string()`][Mnemonic::from_string()] and an empty
+/// password, so the words and checksum are checked and the entropy recomputed rather than trusted.
+/// The language has to be one of the built in [`Language`][Language]s, a `Mnemonic` using a
+/// custom word list can be serialized but not read back this way.
+///
+/// [Language]: ../language/enum.Language.html
+/// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for Mnemonic {
+    fn deserialize<D>(deserializer: D) -> Result<Mnemonic, D::Error> where D: Deserializer<'de> {
+        let serialized = SerializedMnemonic::deserialize(deserializer)?;
+
+        let language = Language::from_name(&serialized.language)
+            .ok_or_else(|| D::Error::custom(format!("unknown word list language \"{}\"", serialized.language)))?;
+
+        Mnemonic::from_string(serialized.phrase.into_owned(), language, String::new()).map_err(D::Error::custom)
+    }
+}
+
+/// Only shows the language, the phrase, password, entropy and seed are all secret
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("language", &self.language())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Clears the entropy, phrase and words when the `zeroize` feature is enabled, the
+/// [`Seed`][Seed] and a pending password clear themselves
+///
+/// [Seed]: ../seed/struct.Seed.html
+#[cfg(feature = "zeroize")]
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.entropy.zeroize();
+        self.string.zeroize();
+
+        if let Some(words) = self.words.get_mut() {
+            words.zeroize();
+        }
+    }
+}
@@ -1,5 +1,9 @@
+use std::prelude::v1::*;
+
 use ::error::{Error, ErrorKind};
+use ::util::split_phrase;
 use std::fmt;
+use std::str::FromStr;
 
 /// Determines the number of words that will be present in a [`Mnemonic`][Mnemonic] phrase
 ///
@@ -27,8 +31,9 @@ use std::fmt;
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 /// [Seed]: ../seed/struct.Seed.html
 ///
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 pub enum MnemonicType {
+    #[default]
     Type12Words,
     Type15Words,
     Type18Words,
@@ -40,7 +45,7 @@ impl MnemonicType {
     /// Get a `MnemonicType` for a mnemonic phrase with a specific number of words
     ///
     /// Specifying a word count not provided for by the BIP39 standard will return an `Error`
-    /// of kind `ErrorKind::InvalidWordLength`.
+    /// of kind `ErrorKind::InvalidWordCount` with the count.
     ///
     /// # Example
     /// ```
@@ -56,7 +61,7 @@ impl MnemonicType {
             18 => MnemonicType::Type18Words,
             21 => MnemonicType::Type21Words,
             24 => MnemonicType::Type24Words,
-            _ => { return Err(ErrorKind::InvalidWordLength.into()) }
+            _ => { return Err(ErrorKind::InvalidWordCount(size).into()) }
         };
 
         Ok(mnemonic_type)
@@ -87,14 +92,43 @@ impl MnemonicType {
         Ok(mnemonic_type)
     }
 
+    /// Get a `MnemonicType` for an entropy size given in bits
+    ///
+    /// BIP39 entropy is always a multiple of 32 bits, from 128 to 256. Anything else, including
+    /// bit counts that aren't even a whole number of bytes like 129, returns an `Error` of kind
+    /// `ErrorKind::InvalidEntropyBits`.
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// let mnemonic_type = MnemonicType::for_entropy_bits(256).unwrap();
+    ///
+    /// assert!(MnemonicType::for_entropy_bits(129).is_err());
+    /// ```
+    // `usize::is_multiple_of` needs a much newer compiler than the rest of the crate
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn for_entropy_bits(bits: usize) -> Result<MnemonicType, Error> {
+
+        if bits % 32 != 0 {
+            return Err(ErrorKind::InvalidEntropyBits(bits).into())
+        }
+
+        MnemonicType::for_key_size(bits).map_err(|_| ErrorKind::InvalidEntropyBits(bits).into())
+    }
+
     /// Get a `MnemonicType` for an existing mnemonic phrase
     ///
     /// This can be used when you need information about a mnemonic phrase based on the number of
     /// words, for example you can get the entropy value using [`MnemonicType::entropy_bits`][MnemonicType::entropy_bits()].
     ///
     /// Specifying a phrase that does not match one of the standard BIP39 phrase lengths will return
-    /// an `Error` of kind `ErrorKind::InvalidWordLength`. The phrase will not be validated in any
-    /// other way.
+    /// an `Error` of kind `ErrorKind::InvalidWordCount` with the number of words. The phrase will
+    /// not be validated in any other way.
+    ///
+    /// Words are counted the same way [`Mnemonic::from_string()`][Mnemonic::from_string()] splits
+    /// them, on any run of whitespace, which includes the ideographic space used in Japanese
+    /// phrases. Leading and trailing whitespace doesn't count as a word.
     ///
     /// # Example
     /// ```
@@ -108,26 +142,20 @@ impl MnemonicType {
     /// ```
     ///
     /// [MnemonicType::entropy_bits()]: ../mnemonic_type/struct.MnemonicType.html#method.entropy_bits
+    /// [Mnemonic::from_string()]: ../mnemonic/struct.Mnemonic.html#method.from_string
     pub fn for_phrase<S>(phrase: S) -> Result<MnemonicType, Error> where S: Into<String> {
 
         let m = phrase.into();
 
-        let v: Vec<&str> = m.split(" ").into_iter().collect();
-
-        let mnemonic_type = match v.len() {
-            12 => MnemonicType::Type12Words,
-            15 => MnemonicType::Type15Words,
-            18 => MnemonicType::Type18Words,
-            21 => MnemonicType::Type21Words,
-            24 => MnemonicType::Type24Words,
-            _ => { return Err(ErrorKind::InvalidWordLength.into()) }
-        };
+        let word_count = split_phrase(&m).count();
 
-        Ok(mnemonic_type)
+        MnemonicType::for_word_count(word_count)
     }
 
     /// Return the number of entropy+checksum bits
     ///
+    /// This is always `entropy_bits() + checksum_bits()`, and `word_count() * 11` since every word
+    /// encodes 11 bits.
     ///
     /// # Example
     /// ```
@@ -138,6 +166,8 @@ impl MnemonicType {
     /// let mnemonic_type = MnemonicType::for_phrase(test_mnemonic).unwrap();
     ///
     /// let total_bits = mnemonic_type.total_bits();
+    ///
+    /// assert_eq!(total_bits, mnemonic_type.entropy_bits() + mnemonic_type.checksum_bits());
     /// ```
     pub fn total_bits(&self) -> usize {
 
@@ -227,16 +257,94 @@ impl MnemonicType {
 
         word_count
     }
-}
 
-impl Default for MnemonicType {
-    fn default() -> MnemonicType {
-        MnemonicType::Type12Words
+    /// Return a short user facing description of the key strength, like `"Standard (128-bit)"`
+    ///
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// let mnemonic_type = MnemonicType::Type24Words;
+    ///
+    /// assert_eq!(mnemonic_type.strength_label(), "Maximum (256-bit)");
+    /// ```
+    pub fn strength_label(&self) -> &'static str {
+
+        let strength_label: &'static str = match *self {
+            MnemonicType::Type12Words => "Standard (128-bit)",
+            MnemonicType::Type15Words => "Enhanced (160-bit)",
+            MnemonicType::Type18Words => "Strong (192-bit)",
+            MnemonicType::Type21Words => "Very strong (224-bit)",
+            MnemonicType::Type24Words => "Maximum (256-bit)"
+        };
+
+        strength_label
+    }
+
+    /// Return short user facing guidance on what this phrase length is suited for
+    ///
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// let mnemonic_type = MnemonicType::Type12Words;
+    ///
+    /// println!("{}", mnemonic_type.recommended_use());
+    /// ```
+    pub fn recommended_use(&self) -> &'static str {
+
+        let recommended_use: &'static str = match *self {
+            MnemonicType::Type12Words => "128-bit: standard wallets and everyday amounts",
+            MnemonicType::Type15Words => "160-bit: standard wallets with extra margin",
+            MnemonicType::Type18Words => "192-bit: savings and larger balances",
+            MnemonicType::Type21Words => "224-bit: long term savings",
+            MnemonicType::Type24Words => "256-bit: high-value funds and cold storage"
+        };
+
+        recommended_use
     }
 }
 
+/// Formats as the word count and entropy size, like `12 words (128bits)`
+///
+/// This is the canonical string form of a `MnemonicType`, and is what [`FromStr`][FromStr]
+/// parses back.
+///
+/// [FromStr]: #impl-FromStr-for-MnemonicType
 impl fmt::Display for MnemonicType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} words ({}bits)", self.word_count(), self.entropy_bits())
     }
 }
+
+/// Parses the canonical string form written by `Display`, like `12 words (128bits)`
+///
+/// Anything else, including a word count and entropy size that don't belong together, returns
+/// an `Error` of kind `ErrorKind::InvalidMnemonicType`.
+///
+/// # Example
+///
+/// ```
+/// use bip39::MnemonicType;
+///
+/// let mnemonic_type: MnemonicType = "24 words (256bits)".parse().unwrap();
+///
+/// assert_eq!(mnemonic_type, MnemonicType::Type24Words);
+/// assert_eq!(mnemonic_type.to_string().parse::<MnemonicType>().unwrap(), mnemonic_type);
+/// ```
+impl FromStr for MnemonicType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<MnemonicType, Error> {
+
+        let types = [MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                     MnemonicType::Type21Words, MnemonicType::Type24Words];
+
+        types.iter()
+            .find(|mnemonic_type| mnemonic_type.to_string() == s)
+            .cloned()
+            .ok_or_else(|| ErrorKind::InvalidMnemonicType(s.to_owned()).into())
+    }
+}
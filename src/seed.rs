@@ -1,15 +1,76 @@
-use ::crypto::{pbkdf2};
+use std::prelude::v1::*;
 
-use data_encoding::HEXUPPER;
+use ::crypto::{constant_time_eq, hmac_sha512, pbkdf2, sha256, PBKDF2_BYTES, PBKDF2_ROUNDS};
+use ::master_key::MasterKey;
+use ::error::{Error, ErrorKind};
+use ::sync::{self, Mutex, OnceLock};
+use ::util::base58_encode;
+#[cfg(feature = "altcoin")]
+use ::crypto::pbkdf2_sha256;
+#[cfg(feature = "altcoin")]
+use ::mnemonic::Mnemonic;
+
+use std::fmt;
+use std::str;
+
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE, HEXUPPER};
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(feature = "mlock")]
+use ::locked::LockedBytes;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Order of the secp256k1 curve, BIP32 private keys must be below it
+static SECP256K1_ORDER: [u8; 32] = [
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+    0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
+];
+
+/// Storage for the seed bytes and their hex, locked into RAM with the `mlock` feature
+#[cfg(feature = "mlock")]
+type SeedBytes = LockedBytes;
+#[cfg(not(feature = "mlock"))]
+type SeedBytes = Vec<u8>;
+
+/// Encodings a [`Seed`][Seed] can be exported in with [`Seed::to_wallet_format()`][Seed::to_wallet_format()]
+///
+/// [Seed]: ../seed/struct.Seed.html
+/// [Seed::to_wallet_format()]: ../seed/struct.Seed.html#method.to_wallet_format
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WalletFormat {
+    /// Lowercase hex of the raw seed bytes
+    Hex,
+    /// Base58Check of the seed bytes, prefixed with the given version byte
+    Base58Check(u8),
+}
 
 /// The secret value used to derive HD wallet addresses from a [`Mnemonic`][Mnemonic] phrase.
 ///
-/// It cannot be created directly, you must create a [`Mnemonic`][Mnemonic] instance and get the seed from
-/// it with [`Mnemonic::get_seed()`][Mnemonic::get_seed()].
+/// The usual way to get one is from a [`Mnemonic`][Mnemonic], with
+/// [`Mnemonic::as_seed()`][Mnemonic::as_seed()] or [`Mnemonic::get_seed()`][Mnemonic::get_seed()].
+/// A `Mnemonic` can't be created from an invalid phrase, so a seed obtained this way always comes
+/// from a valid, intact phrase.
 ///
-/// Because it is not possible to create a [`Mnemonic`][Mnemonic] instance that is invalid, it is
-/// therefore impossible to have a [`Seed`][Seed] instance that is invalid. This guarantees that only
-/// a valid, intact mnemonic phrase can be used to derive HD wallet addresses.
+/// The other constructors give no such guarantee:
+///
+/// * [`Seed::bip39()`][Seed::bip39()] derives the standard seed from any string, without checking
+///   its words or checksum.
+/// * [`Seed::generate_with_iterations()`][Seed::generate_with_iterations()] does the same with a
+///   non-standard number of PBKDF2 rounds.
+/// * [`Seed::from_hex()`][Seed::from_hex()] rebuilds a stored seed. It only checks that the input
+///   is 128 hex characters, there is no way to tell whether the bytes came from a valid phrase, or
+///   from a phrase at all.
+/// * `Seed::generate_sha256()`, with the `altcoin` feature, derives a non-standard 32 byte seed.
+///   It is returned as a separate `Sha256Seed`, never as a `Seed`, so a `Seed` is always 64 bytes.
+///
+/// The seed is derived from the *text* of the phrase, not from its entropy. The same entropy
+/// written out with word lists for two different languages gives two different phrases, and so two
+/// different seeds and two unrelated wallets. Translating a phrase into another language does not
+/// preserve the wallet, even though [`Mnemonic::same_entropy()`][Mnemonic::same_entropy()] is true
+/// for the two phrases.
 ///
 /// To get the raw byte value use [`Seed::as_bytes()`][Seed::as_bytes()], or the hex representation
 /// with [`Seed::as_hex()`][Seed::as_hex()]. These can be used to derive HD wallet addresses using
@@ -17,35 +78,205 @@ use data_encoding::HEXUPPER;
 /// standard).
 ///
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Mnemonic::as_seed()]: ../mnemonic/struct.Mnemonic.html#method.as_seed
 /// [Mnemonic::get_seed()]: ../mnemonic/struct.Mnemonic.html#method.get_seed
-/// [Seed]: ../seed/struct.Seed.html
+/// [Mnemonic::same_entropy()]: ../mnemonic/struct.Mnemonic.html#method.same_entropy
 /// [Seed::as_bytes()]: ../seed/struct.Seed.html#method.as_bytes
 /// [Seed::as_hex()]: ../seed/struct.Seed.html#method.as_hex
+/// [Seed::bip39()]: ../seed/struct.Seed.html#method.bip39
+/// [Seed::generate_with_iterations()]: ../seed/struct.Seed.html#method.generate_with_iterations
+/// [Seed::from_hex()]: ../seed/struct.Seed.html#method.from_hex
 ///
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Seed {
-    bytes: Vec<u8>,
-    hex: String,
+    bytes: SeedBytes,
+    // uppercase hex of `bytes` for `Seed::as_hex()`, kept in the same locked storage since it is
+    // just as secret
+    hex: SeedBytes,
 
 }
 
 impl Seed {
 
-    /// Generates the seed from the original entropy used to create the [`Mnemonic`][Mnemonic] and the password.
+    /// Wrap derived or decoded seed bytes, along with their hex
+    fn from_bytes(seed_value: Vec<u8>) -> Seed {
+
+        let hex = HEXUPPER.encode(&seed_value);
+
+        Seed {
+            bytes: SeedBytes::from(seed_value),
+            hex: SeedBytes::from(hex.into_bytes()),
+        }
+    }
+
+    /// Generates the seed from the phrase of a [`Mnemonic`][Mnemonic] and the password.
+    ///
+    /// Both are NFKD normalized here, callers pass them exactly as the user gave them.
     ///
     /// Cannot be used outside the crate, in order to guarantee correctness
     /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-    pub(crate) fn generate(entropy: &[u8],
+    pub(crate) fn generate(phrase: &str,
                            password: &str) -> Seed {
 
-        let salt = format!("mnemonic{}", password);
-        let seed_value = pbkdf2(entropy, salt);
-        let hex = HEXUPPER.encode(seed_value.as_ref());
+        Seed::derive(phrase, password, PBKDF2_ROUNDS)
+    }
 
-        Seed {
-            bytes: seed_value,
-            hex: hex,
+    /// Derives a seed from a phrase and passphrase like [`Seed::bip39()`][Seed::bip39()], but with
+    /// `iterations` rounds of PBKDF2 instead of the 2048 BIP39 requires
+    ///
+    /// **Only 2048 rounds is BIP39.** Any other count gives a seed no standard wallet will
+    /// reproduce, this is meant for test harnesses (where fewer rounds make tests faster) and
+    /// experimental derivations. As with `Seed::bip39()`, the phrase is not validated, and both
+    /// strings are NFKD normalized.
+    ///
+    /// PBKDF2 needs at least one round, `iterations` of 0 returns an `Error` of kind
+    /// `ErrorKind::InvalidIterations`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Seed;
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    ///
+    /// let standard = Seed::generate_with_iterations(phrase, "TREZOR", 2048).unwrap();
+    /// assert_eq!(standard.as_bytes(), Seed::bip39(phrase, "TREZOR").as_bytes());
+    ///
+    /// let fast = Seed::generate_with_iterations(phrase, "TREZOR", 1).unwrap();
+    /// assert_ne!(fast.as_bytes(), standard.as_bytes());
+    ///
+    /// assert!(Seed::generate_with_iterations(phrase, "TREZOR", 0).is_err());
+    /// ```
+    ///
+    /// [Seed::bip39()]: ../seed/struct.Seed.html#method.bip39
+    pub fn generate_with_iterations(mnemonic: &str,
+                                    passphrase: &str,
+                                    iterations: u32) -> Result<Seed, Error> {
+
+        // ring panics on 0 rounds
+        if iterations == 0 {
+            return Err(ErrorKind::InvalidIterations.into())
         }
+
+        Ok(Seed::derive(mnemonic, passphrase, iterations))
+    }
+
+    /// PBKDF2-HMAC-SHA512 with the BIP39 salt, `iterations` must not be 0
+    fn derive(mnemonic: &str,
+              passphrase: &str,
+              iterations: u32) -> Seed {
+
+        // BIP39 puts the passphrase in the salt, the phrase itself is the PBKDF2 password, so
+        // an empty passphrase gives a salt of just "mnemonic". Both must be NFKD normalized
+        // first, or non-ASCII phrases and passphrases give a different seed than other wallets
+        let phrase: String = mnemonic.nfkd().collect();
+        let salt = format!("mnemonic{}", passphrase.nfkd().collect::<String>());
+        let seed_value = pbkdf2(phrase.as_bytes(), salt, iterations);
+
+        Seed::from_bytes(seed_value)
+    }
+
+    /// Derives the BIP39 seed straight from a phrase and passphrase, **without validating the phrase**
+    ///
+    /// Both strings are NFKD normalized, as BIP39 requires, and then run through PBKDF2-HMAC-SHA512
+    /// with 2048 rounds and a salt of `"mnemonic"` followed by the passphrase. For a valid phrase
+    /// the result is identical to [`Mnemonic::as_seed()`][Mnemonic::as_seed()].
+    ///
+    /// Unlike a [`Seed`][Seed] taken from a [`Mnemonic`][Mnemonic], the words and checksum of
+    /// `mnemonic` are never checked, so any string at all produces a seed. This matches tools like Ian Coleman's
+    /// BIP39 page, and is meant for callers that deliberately work with phrases that don't pass
+    /// validation. Anything else should go through [`Mnemonic`][Mnemonic].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Seed;
+    ///
+    /// let seed = Seed::bip39("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", "TREZOR");
+    ///
+    /// assert_eq!(seed.as_bytes().len(), 64);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::as_seed()]: ../mnemonic/struct.Mnemonic.html#method.as_seed
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn bip39(mnemonic: &str,
+                 passphrase: &str) -> Seed {
+
+        Seed::generate(mnemonic, passphrase)
+    }
+
+    /// Generates a 32 byte seed using PBKDF2-HMAC-SHA256 instead of SHA512
+    ///
+    /// **This is not BIP39.** A few altcoins derive their seed this way, and the result is not
+    /// compatible with standard BIP39 wallets: the same phrase and passphrase give a completely
+    /// different (and shorter) seed than [`Mnemonic::as_seed()`][Mnemonic::as_seed()]. Only use it
+    /// for chains that are documented to need it.
+    ///
+    /// The salt and round count are the same as the standard derivation. The result is a
+    /// [`Sha256Seed`][Sha256Seed] rather than a `Seed`, so it can't be passed to anything that
+    /// expects a 64 byte BIP39 seed. Only available with the `altcoin` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Seed, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let seed: [u8; 32] = Seed::generate_sha256(&mnemonic, "").to_array();
+    /// ```
+    ///
+    /// [Mnemonic::as_seed()]: ../mnemonic/struct.Mnemonic.html#method.as_seed
+    /// [Sha256Seed]: ../seed/struct.Sha256Seed.html
+    #[cfg(feature = "altcoin")]
+    pub fn generate_sha256(mnemonic: &Mnemonic,
+                           passphrase: &str) -> Sha256Seed {
+
+        let phrase: String = mnemonic.as_str().nfkd().collect();
+        let salt = format!("mnemonic{}", passphrase.nfkd().collect::<String>());
+        let seed_value = pbkdf2_sha256(phrase.as_bytes(), salt);
+
+        Sha256Seed {
+            bytes: SeedBytes::from(seed_value),
+        }
+    }
+
+    /// Rebuild a seed from its hex representation, as returned by [`Seed::as_hex()`][Seed::as_hex()]
+    ///
+    /// For callers that only stored the derived seed and not the phrase. `hex` must be exactly 128
+    /// hex characters, upper or lower case, for the 64 seed bytes. Any other length returns an
+    /// `Error` of kind `ErrorKind::InvalidSeedLength`, and anything that isn't hex returns
+    /// `ErrorKind::DataDecode`.
+    ///
+    /// Nothing about the seed can be checked, so unlike one derived from a [`Mnemonic`][Mnemonic]
+    /// there is no guarantee it came from a valid phrase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Seed, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let seed = Seed::from_hex(mnemonic.as_seed().as_hex()).unwrap();
+    ///
+    /// assert_eq!(seed.as_bytes(), mnemonic.as_seed().as_bytes());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Seed::as_hex()]: ../seed/struct.Seed.html#method.as_hex
+    pub fn from_hex(hex: &str) -> Result<Seed, Error> {
+
+        if hex.len() != PBKDF2_BYTES * 2 {
+            return Err(ErrorKind::InvalidSeedLength(hex.len()).into())
+        }
+
+        let seed_value = HEXLOWER_PERMISSIVE.decode(hex.as_bytes())?;
+
+        Ok(Seed::from_bytes(seed_value))
     }
 
     /// Get the seed value as a slice
@@ -55,10 +286,175 @@ impl Seed {
 
     }
 
+    /// Copy the seed value into a fixed size array
+    ///
+    /// BIP39 seeds are always 64 bytes, so this never fails, and is convenient for APIs like BIP32
+    /// key derivation that take a `[u8; 64]`. The copy is not locked or cleared on drop like the
+    /// seed's own bytes are, so keep it around no longer than needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let seed: [u8; 64] = mnemonic.as_seed().to_array();
+    ///
+    /// assert_eq!(&seed[..], mnemonic.as_seed().as_bytes());
+    /// ```
+    pub fn to_array(&self) -> [u8; 64] {
+
+        let mut array = [0u8; 64];
+        array.copy_from_slice(self.as_bytes());
+
+        array
+    }
+
+    /// Derive the BIP32 master private key and chain code from this seed
+    ///
+    /// This is `HMAC-SHA512` keyed with `"Bitcoin seed"` over the seed bytes, the left half of the
+    /// result is the private key and the right half the chain code. BIP32 considers the seed
+    /// unusable if the private key is 0 or not below the secp256k1 curve order, which happens with
+    /// a probability of less than 1 in 2<sup>127</sup>, that returns an `Error` of kind
+    /// `ErrorKind::InvalidMasterKey`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let master_key = mnemonic.as_seed().to_master_key().unwrap();
+    ///
+    /// assert_eq!(master_key.chain_code.len(), 32);
+    /// ```
+    pub fn to_master_key(&self) -> Result<MasterKey, Error> {
+
+        let hash = hmac_sha512(b"Bitcoin seed", self.as_bytes());
+
+        let mut master_key = MasterKey {
+            private_key: [0u8; 32],
+            chain_code: [0u8; 32],
+        };
+        master_key.private_key.copy_from_slice(&hash[..32]);
+        master_key.chain_code.copy_from_slice(&hash[32..]);
+
+        // both are big endian, so comparing the bytes compares the numbers
+        if master_key.private_key == [0u8; 32] || master_key.private_key[..] >= SECP256K1_ORDER[..] {
+            return Err(ErrorKind::InvalidMasterKey.into())
+        }
+
+        Ok(master_key)
+    }
+
+    /// Whether the seed bytes are locked into RAM
+    ///
+    /// With the `mlock` feature the seed bytes are locked so they can't be swapped to disk, and
+    /// cleared when the seed is dropped. Locking is best effort: if the OS refuses (for example
+    /// because `RLIMIT_MEMLOCK` is too low) a warning is logged through the `log` crate and the
+    /// seed works as normal, this returns `false` in that case. The hex returned by
+    /// [`Seed::as_hex()`][Seed::as_hex()] is kept in locked memory the same way.
+    ///
+    /// Every seed gets whole pages of its own, which costs a page or two of locked memory per seed
+    /// but means dropping one seed never unlocks the memory of another.
+    ///
+    /// [Seed::as_hex()]: ../seed/struct.Seed.html#method.as_hex
+    #[cfg(feature = "mlock")]
+    pub fn is_memory_locked(&self) -> bool {
+        self.bytes.is_locked()
+    }
+
     /// Get the seed value as a hex string
     pub fn as_hex(&self) -> &str {
 
-        self.hex.as_ref()
+        str::from_utf8(self.hex.as_ref()).expect("seed hex is always ASCII")
+    }
+
+    /// Get the seed value as a lowercase hex string
+    ///
+    /// Note: this allocates a new String
+    pub fn get_hex_lower(&self) -> String {
+
+        HEXLOWER.encode(self.as_bytes())
+    }
+
+    /// Get a value that formats the seed as lowercase hex with `Display`
+    ///
+    /// `Seed` deliberately doesn't implement `Display` itself, so printing the secret has to be
+    /// asked for explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// println!("seed: {}", mnemonic.as_seed().display_hex());
+    /// ```
+    pub fn display_hex(&self) -> impl fmt::Display + '_ {
+
+        HexDisplay(self.as_bytes())
+    }
+
+    /// Get the seed encoded in the format some wallet software expects for importing it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, WalletFormat, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    ///
+    /// let encoded = mnemonic.as_seed().to_wallet_format(WalletFormat::Base58Check(0x80));
+    /// ```
+    pub fn to_wallet_format(&self, format: WalletFormat) -> String {
+
+        match format {
+            WalletFormat::Hex => self.get_hex_lower(),
+            WalletFormat::Base58Check(version) => {
+                let mut payload = Vec::with_capacity(self.bytes.len() + 5);
+                payload.push(version);
+                payload.extend(self.as_bytes());
+
+                let checksum = sha256(&sha256(&payload));
+                payload.extend(&checksum[..4]);
+
+                base58_encode(&payload)
+            }
+        }
+    }
+
+    /// Compare two seeds in constant time
+    ///
+    /// `Seed` deliberately doesn't implement `PartialEq`, comparing the slices from
+    /// [`Seed::as_bytes()`][Seed::as_bytes()] with `==` stops at the first differing byte and so
+    /// leaks how much of a stored seed a guess got right. This always looks at every byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let first = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    /// let second = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+    ///
+    /// assert!(first.as_seed().ct_eq(&first.get_seed()));
+    /// assert!(!first.as_seed().ct_eq(second.as_seed()));
+    /// ```
+    ///
+    /// [Seed::as_bytes()]: ../seed/struct.Seed.html#method.as_bytes
+    pub fn ct_eq(&self, other: &Seed) -> bool {
+
+        constant_time_eq(self.as_bytes(), other.as_bytes())
     }
 
     /// Get an owned [`Seed`][Seed] from this instance
@@ -87,3 +483,194 @@ impl AsRef<str> for Seed {
         self.as_hex()
     }
 }
+
+/// Doesn't show the seed, use [`Seed::display_hex()`][Seed::display_hex()] to print it
+///
+/// [Seed::display_hex()]: ../seed/struct.Seed.html#method.display_hex
+impl fmt::Debug for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Seed").finish_non_exhaustive()
+    }
+}
+
+/// Clears the seed bytes and hex when the `zeroize` feature is enabled
+#[cfg(all(feature = "zeroize", not(feature = "mlock")))]
+impl Drop for Seed {
+    fn drop(&mut self) {
+        // with `mlock` both are a LockedBytes, which already clears itself on drop
+        self.bytes.zeroize();
+        self.hex.zeroize();
+    }
+}
+
+/// The non-standard 32 byte seed some altcoins derive with PBKDF2-HMAC-SHA256
+///
+/// Returned by `Seed::generate_sha256()`, only available with the `altcoin` feature. **This is
+/// not a BIP39 seed**, it is kept apart from [`Seed`][Seed] so it can't be hex encoded, exported
+/// or turned into a BIP32 master key as if it were one. The bytes are locked and cleared on drop
+/// the same way a `Seed`'s are.
+///
+/// [Seed]: ../seed/struct.Seed.html
+#[cfg(feature = "altcoin")]
+#[derive(Clone)]
+pub struct Sha256Seed {
+    bytes: SeedBytes,
+}
+
+#[cfg(feature = "altcoin")]
+impl Sha256Seed {
+
+    /// Get the seed value as a slice
+    pub fn as_bytes(&self) -> &[u8] {
+
+        self.bytes.as_ref()
+    }
+
+    /// Copy the seed value into a fixed size array
+    ///
+    /// As with [`Seed::to_array()`][Seed::to_array()] the copy is not locked or cleared on drop.
+    ///
+    /// [Seed::to_array()]: ../seed/struct.Seed.html#method.to_array
+    pub fn to_array(&self) -> [u8; 32] {
+
+        let mut array = [0u8; 32];
+        array.copy_from_slice(self.as_bytes());
+
+        array
+    }
+
+    /// Get the seed value as a lowercase hex string
+    ///
+    /// Note: this allocates a new String
+    pub fn get_hex_lower(&self) -> String {
+
+        HEXLOWER.encode(self.as_bytes())
+    }
+
+    /// Compare two seeds in constant time, see [`Seed::ct_eq()`][Seed::ct_eq()]
+    ///
+    /// [Seed::ct_eq()]: ../seed/struct.Seed.html#method.ct_eq
+    pub fn ct_eq(&self, other: &Sha256Seed) -> bool {
+
+        constant_time_eq(self.as_bytes(), other.as_bytes())
+    }
+}
+
+#[cfg(feature = "altcoin")]
+impl AsRef<[u8]> for Sha256Seed {
+    fn as_ref(&self) -> &[u8] {
+
+        self.as_bytes()
+    }
+}
+
+/// Doesn't show the seed, use [`Sha256Seed::get_hex_lower()`][Sha256Seed::get_hex_lower()] to print it
+///
+/// [Sha256Seed::get_hex_lower()]: ../seed/struct.Sha256Seed.html#method.get_hex_lower
+#[cfg(feature = "altcoin")]
+impl fmt::Debug for Sha256Seed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Sha256Seed").finish_non_exhaustive()
+    }
+}
+
+/// Clears the seed bytes when the `zeroize` feature is enabled
+#[cfg(all(feature = "altcoin", feature = "zeroize", not(feature = "mlock")))]
+impl Drop for Sha256Seed {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// The seed of a `Mnemonic`, along with the password it is derived with while it is pending
+///
+/// The password is only needed to derive the seed, so it is kept until then and no longer. Once
+/// the seed has been derived the password is dropped, and cleared first with the `zeroize`
+/// feature.
+pub(crate) struct LazySeed {
+    seed: OnceLock<Seed>,
+    // `None` once the seed has been derived
+    password: Mutex<Option<String>>,
+}
+
+impl LazySeed {
+
+    /// A seed that will be derived with `password` the first time it is requested
+    pub(crate) fn pending(password: String) -> LazySeed {
+
+        LazySeed {
+            seed: OnceLock::new(),
+            password: Mutex::new(Some(password)),
+        }
+    }
+
+    /// Get the seed, deriving it from `phrase` and dropping the password the first time
+    pub(crate) fn get_or_derive(&self, phrase: &str) -> &Seed {
+
+        if let Some(seed) = self.seed.get() {
+            return seed
+        }
+
+        {
+            // held until the seed is stored, so whoever takes the lock next sees either the
+            // password or the seed
+            let mut pending = sync::lock(&self.password);
+
+            if let Some(password) = pending.take() {
+                let _ = self.seed.set(Seed::generate(phrase, &password));
+                LazySeed::forget(Some(password));
+            }
+        }
+
+        self.seed.get().expect("the seed is stored before the password is dropped")
+    }
+
+    #[cfg(feature = "zeroize")]
+    fn forget(mut password: Option<String>) {
+        password.zeroize();
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    fn forget(_password: Option<String>) {}
+}
+
+/// Copies the seed, or the password if the seed is still pending
+impl Clone for LazySeed {
+    fn clone(&self) -> LazySeed {
+
+        let password = sync::lock(&self.password);
+
+        if let Some(ref password) = *password {
+            return LazySeed::pending(password.clone())
+        }
+
+        // the seed is stored before the password is dropped, and both happen under the lock
+        let seed = OnceLock::new();
+        let _ = seed.set(self.seed.get().expect("the seed is stored before the password is dropped").clone());
+
+        LazySeed {
+            seed,
+            password: Mutex::new(None),
+        }
+    }
+}
+
+/// Clears a pending password when the `zeroize` feature is enabled
+#[cfg(feature = "zeroize")]
+impl Drop for LazySeed {
+    fn drop(&mut self) {
+        sync::get_mut(&mut self.password).zeroize();
+    }
+}
+
+/// Formats a byte slice as lowercase hex without allocating
+struct HexDisplay<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for HexDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
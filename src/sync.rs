@@ -0,0 +1,211 @@
+//! The synchronization types the crate uses, from std or, without the `std` feature, minimal
+//! versions built on atomics.
+//!
+//! The `no_std` versions only cover what the crate needs. `Mutex` spins rather than blocking,
+//! which is fine since the only one guards a pending password and is held at most while that
+//! seed is derived. `OnceLock` can run its initializer more than once when threads race for it,
+//! the first value stored is kept and the others are dropped, so it is only used for values that
+//! come out the same however many times they are built.
+
+#[cfg(feature = "std")]
+pub(crate) use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+#[cfg(feature = "std")]
+use std::sync::PoisonError;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+pub(crate) use self::atomic::{Mutex, MutexGuard, OnceLock};
+
+/// Lock `mutex`, going ahead if it was poisoned
+///
+/// Nothing the crate locks is ever left halfway through an update by a panic.
+#[cfg(feature = "std")]
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock()
+}
+
+/// Get the value in a `Mutex` that isn't shared, going ahead if it was poisoned
+///
+/// Only needed to clear a pending password on drop.
+#[cfg(all(feature = "std", feature = "zeroize"))]
+pub(crate) fn get_mut<T>(mutex: &mut Mutex<T>) -> &mut T {
+    mutex.get_mut().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(all(not(feature = "std"), feature = "zeroize"))]
+pub(crate) fn get_mut<T>(mutex: &mut Mutex<T>) -> &mut T {
+    mutex.get_mut()
+}
+
+#[cfg(not(feature = "std"))]
+mod atomic {
+    use std::prelude::v1::*;
+
+    use std::cell::UnsafeCell;
+    use std::fmt;
+    use std::hint;
+    use std::marker::PhantomData;
+    use std::ops::{Deref, DerefMut};
+    use std::ptr;
+    use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+    /// A cell that is written at most once, holding its value in a `Box`
+    pub(crate) struct OnceLock<T> {
+        value: AtomicPtr<T>,
+        _owns: PhantomData<Box<T>>,
+    }
+
+    // the value is shared between threads once it is stored, and moves with the cell
+    unsafe impl<T: Send + Sync> Sync for OnceLock<T> {}
+    unsafe impl<T: Send> Send for OnceLock<T> {}
+
+    impl<T> OnceLock<T> {
+
+        pub(crate) const fn new() -> OnceLock<T> {
+
+            OnceLock {
+                value: AtomicPtr::new(ptr::null_mut()),
+                _owns: PhantomData,
+            }
+        }
+
+        pub(crate) fn get(&self) -> Option<&T> {
+
+            // a stored value is only freed when the cell is dropped, so it outlives `&self`
+            unsafe { self.value.load(Ordering::Acquire).as_ref() }
+        }
+
+        /// Store `value` if the cell is empty, otherwise hand it back
+        pub(crate) fn set(&self, value: T) -> Result<(), T> {
+
+            let value = Box::into_raw(Box::new(value));
+
+            match self.value.compare_exchange(ptr::null_mut(), value, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => Ok(()),
+                // never stored, so this is still the only pointer to it
+                Err(_) => Err(*unsafe { Box::from_raw(value) }),
+            }
+        }
+
+        pub(crate) fn get_or_init<F>(&self, f: F) -> &T where F: FnOnce() -> T {
+
+            if let Some(value) = self.get() {
+                return value
+            }
+
+            // another thread may have stored a value in the meantime, it is kept rather than ours
+            let _ = self.set(f());
+
+            self.get().expect("a value was just stored")
+        }
+
+        #[cfg(feature = "zeroize")]
+        pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+
+            // `&mut self` rules out any other reference to the value
+            unsafe { self.value.get_mut().as_mut() }
+        }
+    }
+
+    impl<T> Drop for OnceLock<T> {
+        fn drop(&mut self) {
+
+            let value = *self.value.get_mut();
+
+            if !value.is_null() {
+                // came from `Box::into_raw()` in `set()`, and nothing else can reach it any more
+                drop(unsafe { Box::from_raw(value) });
+            }
+        }
+    }
+
+    impl<T> Default for OnceLock<T> {
+        fn default() -> OnceLock<T> {
+            OnceLock::new()
+        }
+    }
+
+    impl<T: Clone> Clone for OnceLock<T> {
+        fn clone(&self) -> OnceLock<T> {
+
+            let cell = OnceLock::new();
+            if let Some(value) = self.get() {
+                let _ = cell.set(value.clone());
+            }
+            cell
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for OnceLock<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_tuple("OnceLock").field(&self.get()).finish()
+        }
+    }
+
+    /// A spin lock
+    pub(crate) struct Mutex<T> {
+        locked: AtomicBool,
+        value: UnsafeCell<T>,
+    }
+
+    // the value is only reached through a guard, and there is at most one guard at a time
+    unsafe impl<T: Send> Sync for Mutex<T> {}
+
+    impl<T> Mutex<T> {
+
+        pub(crate) const fn new(value: T) -> Mutex<T> {
+
+            Mutex {
+                locked: AtomicBool::new(false),
+                value: UnsafeCell::new(value),
+            }
+        }
+
+        pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+
+            while self.locked.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+                hint::spin_loop();
+            }
+
+            MutexGuard { mutex: self }
+        }
+
+        #[cfg(feature = "zeroize")]
+        pub(crate) fn get_mut(&mut self) -> &mut T {
+            self.value.get_mut()
+        }
+    }
+
+    /// Unlocks its `Mutex` when dropped
+    pub(crate) struct MutexGuard<'a, T: 'a> {
+        mutex: &'a Mutex<T>,
+    }
+
+    impl<'a, T> Deref for MutexGuard<'a, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            // the guard holds the lock
+            unsafe { &*self.mutex.value.get() }
+        }
+    }
+
+    impl<'a, T> DerefMut for MutexGuard<'a, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            // the guard holds the lock
+            unsafe { &mut *self.mutex.value.get() }
+        }
+    }
+
+    impl<'a, T> Drop for MutexGuard<'a, T> {
+        fn drop(&mut self) {
+            self.mutex.locked.store(false, Ordering::Release);
+        }
+    }
+}
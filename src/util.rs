@@ -1,3 +1,8 @@
+use std::prelude::v1::*;
+
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
 
 pub(crate) fn bit_from_u16_as_u11(input: u16, position: u16) -> bool {
     if position < 11 {
@@ -6,3 +11,96 @@ pub(crate) fn bit_from_u16_as_u11(input: u16, position: u16) -> bool {
         false
     }
 }
+
+static BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Bitcoin style base58 encoding, leading zero bytes become leading `1`s
+pub(crate) fn base58_encode(input: &[u8]) -> String {
+
+    let zeros = input.iter().take_while(|b| **b == 0).count();
+
+    // base58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+    for byte in &input[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut encoded = String::with_capacity(zeros + digits.len());
+    for _ in 0..zeros {
+        encoded.push('1');
+    }
+    for digit in digits.iter().rev() {
+        encoded.push(BASE58_ALPHABET[*digit as usize] as char);
+    }
+
+    encoded
+}
+
+/// Split a phrase into its words, on any run of whitespace
+///
+/// This covers the ideographic space used between Japanese words as well as the ASCII space,
+/// and ignores leading and trailing whitespace, so phrases copied from documents or emails with
+/// line breaks or double spaces still split into the right words.
+pub(crate) fn split_phrase(phrase: &str) -> impl Iterator<Item = &str> {
+    phrase.split_whitespace()
+}
+
+/// NFKD normalize a word or phrase, the form BIP39 word lists are stored in
+///
+/// ASCII text is already NFKD, so it is borrowed rather than copied.
+pub(crate) fn nfkd(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.nfkd().collect())
+    }
+}
+
+/// Put a word or prefix in the form prefixes are compared in
+///
+/// This is NFKC: the same compatibility mapping as NFKD, but composed, so an accented letter, a
+/// kana with its voicing mark or a Hangul syllable counts as one character rather than two or
+/// three. ASCII text is borrowed rather than copied.
+pub(crate) fn prefix_form(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.nfkc().collect())
+    }
+}
+
+/// Rebuild a phrase with exactly one `separator` between each of its words
+pub(crate) fn normalize_whitespace(phrase: &str, separator: &str) -> String {
+    split_phrase(phrase).collect::<Vec<&str>>().join(separator)
+}
+
+/// Number of single character insertions, deletions or substitutions needed to turn `a` into `b`
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+
+    let b: Vec<char> = b.chars().collect();
+
+    // distances from the part of `a` seen so far to every prefix of `b`
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
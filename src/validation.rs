@@ -0,0 +1,96 @@
+use std::prelude::v1::*;
+
+use ::error::Error;
+use ::indices::{checksum_matches, indices_to_entropy};
+use ::mnemonic::{Mnemonic, WordList};
+use ::mnemonic_type::MnemonicType;
+
+/// Check that a phrase is valid for `word_list`, without creating a [`Mnemonic`][Mnemonic]
+///
+/// This is the check behind [`Mnemonic::validate()`][Mnemonic::validate()], for services that
+/// check phrases in bulk. The phrase is borrowed rather than copied, words are looked up in the
+/// word map cached on `word_list`, and no seed is derived, so the cost per phrase is a single
+/// SHA256 hash. Errors are the same as for `Mnemonic::validate()`.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{validate_phrase, Language};
+///
+/// let word_list = Language::English.word_list();
+///
+/// let phrases = ["park remain person kitchen mule spell knee armed position rail grid ankle",
+///                "crop cash unable insane eight faith inflict route frame loud box vibrant"];
+///
+/// assert!(phrases.iter().all(|phrase| validate_phrase(phrase, word_list).is_ok()));
+/// ```
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+pub fn validate_phrase(phrase: &str,
+                       word_list: &WordList) -> Result<(), Error> {
+
+    let (mnemonic_type, indices) = Mnemonic::phrase_indices(phrase, word_list)?;
+
+    if checksum_matches(&indices, mnemonic_type) {
+        return Ok(())
+    }
+
+    // only invalid phrases pay for working out which checksum was expected
+    indices_to_entropy(&indices, mnemonic_type).and(Ok(()))
+}
+
+/// Outcome of the checksum check in a [`ValidationReport`][ValidationReport]
+///
+/// [ValidationReport]: ../validation/struct.ValidationReport.html
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    /// The checksum matches the entropy
+    Valid,
+    /// The checksum doesn't match the entropy
+    Invalid,
+    /// The checksum couldn't be checked, because the word count or some of the words are invalid
+    ///
+    /// This is also the status when the valid words are already wrong, see
+    /// [`ValidationReport`][ValidationReport] for why.
+    ///
+    /// [ValidationReport]: ../validation/struct.ValidationReport.html
+    Unchecked,
+}
+
+/// Everything that is wrong with a mnemonic phrase, from [`Mnemonic::validate_detailed()`][Mnemonic::validate_detailed()]
+///
+/// Unlike [`Mnemonic::validate()`][Mnemonic::validate()], which stops at the first problem, this
+/// collects every problem it can find in a single pass, which is what a form validator needs to
+/// highlight them all at once.
+///
+/// One thing it can't report is a bad checksum alongside an invalid word. The checksum covers
+/// every word, and an invalid word could be meant as any of the 2048 in the list. For a 12 word
+/// phrase 1 in 16 of those give a matching checksum, 1 in 256 for 24 words, so whatever the other
+/// words are, a phrase with an invalid word can almost always be corrected into one that passes.
+/// The checksum is only checked once every word is valid, until then it is
+/// [`ChecksumStatus::Unchecked`][ChecksumStatus::Unchecked].
+///
+/// [ChecksumStatus::Unchecked]: ../validation/enum.ChecksumStatus.html#variant.Unchecked
+/// [Mnemonic::validate_detailed()]: ../mnemonic/struct.Mnemonic.html#method.validate_detailed
+/// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+///
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    /// Number of words in the phrase
+    pub word_count: usize,
+    /// The `MnemonicType` for the word count, `None` if it isn't a standard BIP39 phrase length
+    pub mnemonic_type: Option<MnemonicType>,
+    /// Position and text of each word that isn't in the word list, in phrase order
+    pub invalid_words: Vec<(usize, String)>,
+    /// Whether the checksum matches, it is only checked when everything else is valid
+    pub checksum: ChecksumStatus,
+}
+
+impl ValidationReport {
+    /// Whether the phrase is completely valid
+    pub fn is_ok(&self) -> bool {
+        self.mnemonic_type.is_some() && self.invalid_words.is_empty() && self.checksum == ChecksumStatus::Valid
+    }
+}
@@ -0,0 +1,126 @@
+use std::prelude::v1::*;
+
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "std")]
+use std::fs::File;
+
+#[cfg(feature = "std")]
+use serde_json::de;
+
+use data_encoding::HEXUPPER;
+
+use ::error::{Error, ErrorKind};
+use ::mnemonic::{Mnemonic, WordList};
+use ::mnemonic_type::MnemonicType;
+
+/// A single entropy → phrase → seed test vector
+///
+/// Vector files are a JSON array of these, in the same shape as `tests/vectors/english.json`:
+///
+/// ```json
+/// [
+///   {
+///     "entropy": "00000000000000000000000000000000",
+///     "mnemonic": "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+///     "passphrase": "TREZOR",
+///     "seed": "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+///   }
+/// ]
+/// ```
+///
+/// Hex values may be upper or lower case, and `passphrase` defaults to an empty string if it is
+/// left out.
+///
+/// This is mostly useful for maintainers of custom or unofficial word lists, who can check their
+/// list against their own vectors using exactly the same logic as the crate does.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestVector {
+    pub entropy: String,
+    pub mnemonic: String,
+    #[serde(default)]
+    pub passphrase: String,
+    pub seed: String,
+}
+
+impl TestVector {
+
+    /// Load a list of test vectors from a JSON file
+    ///
+    /// A file that can't be opened returns an `Error` of kind `ErrorKind::InvalidFile`, and
+    /// malformed JSON returns `ErrorKind::WordListParse` with the parser's message, the same as a
+    /// malformed word list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::TestVector;
+    /// use std::path::PathBuf;
+    /// use std::env;
+    ///
+    /// let mut path = PathBuf::from(env::current_dir().unwrap());
+    /// path.push("tests/vectors/english.json");
+    ///
+    /// let vectors = TestVector::load(path).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn load(path: PathBuf) -> Result<Vec<TestVector>, Error> {
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(_) => return Err(ErrorKind::InvalidFile.into())
+        };
+
+        let vectors: Vec<TestVector> = de::from_reader(file)?;
+
+        Ok(vectors)
+    }
+
+    /// Run the vector through the crate using the given word list
+    ///
+    /// Checks that the entropy produces the expected phrase, that the phrase validates and gives back
+    /// the same entropy, and that the phrase and passphrase produce the expected seed. The first
+    /// mismatch is returned as an `Error` of kind `ErrorKind::TestVectorMismatch`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, TestVector};
+    /// use std::path::PathBuf;
+    /// use std::env;
+    ///
+    /// let mut path = PathBuf::from(env::current_dir().unwrap());
+    /// path.push("tests/vectors/english.json");
+    ///
+    /// for vector in TestVector::load(path).unwrap() {
+    ///     vector.check(Language::English.word_list()).unwrap();
+    /// }
+    /// ```
+    pub fn check(&self, word_list: &WordList) -> Result<(), Error> {
+        let entropy = self.entropy.to_uppercase();
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4)?;
+
+        let from_entropy = Mnemonic::from_entropy_with_word_list(&HEXUPPER.decode(entropy.as_ref())?, mnemonic_type,
+                                                                 word_list, &*self.passphrase)?;
+        if from_entropy.as_str() != self.mnemonic {
+            return Err(ErrorKind::TestVectorMismatch("mnemonic".into(),
+                                                     self.mnemonic.clone(),
+                                                     from_entropy.get_string()).into())
+        }
+
+        let from_string = Mnemonic::from_string_with_word_list(&*self.mnemonic, word_list, &*self.passphrase)?;
+        if from_string.get_entropy_hex() != entropy {
+            return Err(ErrorKind::TestVectorMismatch("entropy".into(),
+                                                     entropy,
+                                                     from_string.get_entropy_hex()).into())
+        }
+
+        let seed = self.seed.to_uppercase();
+        if from_string.as_seed().as_hex() != seed {
+            return Err(ErrorKind::TestVectorMismatch("seed".into(),
+                                                     seed,
+                                                     from_string.as_seed().as_hex().to_owned()).into())
+        }
+
+        Ok(())
+    }
+}
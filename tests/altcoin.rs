@@ -0,0 +1,19 @@
+#![cfg(feature = "altcoin")]
+
+extern crate bip39;
+
+use ::bip39::{Language, Mnemonic, Seed};
+
+#[test]
+fn generate_sha256_known_vector() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+
+    let seed = Seed::generate_sha256(&mnemonic, "TREZOR");
+
+    assert_eq!(seed.get_hex_lower(), "076a3ef06f19d2c0aa98df2deb5a8e6d4f6ce273bb410ea9076bbbf1aa008942");
+    assert_eq!(&seed.to_array()[..], seed.as_bytes());
+    assert_ne!(seed.as_bytes(), &mnemonic.as_seed().as_bytes()[..32]);
+}
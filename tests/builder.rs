@@ -0,0 +1,99 @@
+extern crate bip39;
+#[cfg(feature = "std")]
+extern crate rand;
+
+#[cfg(feature = "std")]
+use rand::{SeedableRng, XorShiftRng};
+use ::bip39::{Error, ErrorKind, Language, Mnemonic, MnemonicBuilder, MnemonicType};
+
+#[test]
+fn build_encodes_the_given_entropy() {
+    let entropy = [0x7fu8; 32];
+
+    let mnemonic = MnemonicBuilder::new()
+        .language(Language::Japanese)
+        .password("TREZOR")
+        .entropy(&entropy[..])
+        .build()
+        .unwrap();
+
+    let expected = Mnemonic::from_entropy(&entropy, MnemonicType::Type24Words, Language::Japanese, "TREZOR").unwrap();
+
+    assert_eq!(mnemonic.as_str(), expected.as_str());
+    assert_eq!(mnemonic.as_seed().as_bytes(), expected.as_seed().as_bytes());
+
+    // an explicit type has to agree with the entropy
+    let mnemonic = MnemonicBuilder::new()
+        .mnemonic_type(MnemonicType::Type24Words)
+        .entropy(&entropy[..])
+        .build()
+        .unwrap();
+    assert_eq!(mnemonic.word_count(), 24);
+
+    match MnemonicBuilder::new().mnemonic_type(MnemonicType::Type12Words).entropy(&entropy[..]).build() {
+        Err(Error(ErrorKind::InvalidEntropyLength(256, MnemonicType::Type12Words), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match MnemonicBuilder::new().entropy(vec![0u8; 17]).build() {
+        Err(Error(ErrorKind::InvalidEntropyBits(136), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match MnemonicBuilder::new().mnemonic_type(MnemonicType::Type12Words).entropy(vec![0u8; 17]).build() {
+        Err(Error(ErrorKind::InvalidEntropyBits(136), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn generate_uses_the_given_options() {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    let mnemonic = MnemonicBuilder::new()
+        .mnemonic_type(MnemonicType::Type18Words)
+        .language(Language::Japanese)
+        .password("TREZOR")
+        .generate_with_rng(&mut rng)
+        .unwrap();
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let expected = Mnemonic::new_with_rng(MnemonicType::Type18Words, Language::Japanese, "TREZOR", &mut rng).unwrap();
+
+    assert_eq!(mnemonic.as_str(), expected.as_str());
+    assert_eq!(mnemonic.as_seed().as_bytes(), expected.as_seed().as_bytes());
+
+    // defaults to a 12 word English phrase
+    let mnemonic = MnemonicBuilder::new().generate().unwrap();
+    assert_eq!(mnemonic.word_count(), 12);
+    assert_eq!(mnemonic.language(), Some(Language::English));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn entropy_and_generate_are_mutually_exclusive() {
+    match MnemonicBuilder::new().entropy(vec![0u8; 16]).generate() {
+        Err(Error(ErrorKind::InvalidBuilderOptions(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match MnemonicBuilder::new().mnemonic_type(MnemonicType::Type12Words).build() {
+        Err(Error(ErrorKind::InvalidBuilderOptions(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn debug_does_not_show_password_or_entropy() {
+    let builder = MnemonicBuilder::new()
+        .language(Language::Japanese)
+        .password("TREZOR")
+        .entropy(vec![0xabu8; 16]);
+
+    let formatted = format!("{:?}", builder);
+
+    assert_eq!(formatted, "MnemonicBuilder { mnemonic_type: None, language: Japanese, .. }");
+    assert!(!formatted.contains("TREZOR"));
+    assert!(!formatted.contains("171"));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+extern crate bip39;
+extern crate rand;
+
+use rand::{SeedableRng, XorShiftRng};
+use ::bip39::{Bip39Context, Language, MnemonicType, Normalization, Seed};
+
+#[test]
+fn context_validates_its_own_output() {
+    let word_list = Language::English.word_list();
+    let rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    let mut context = Bip39Context::new(word_list.clone(), MnemonicType::Type18Words, Normalization::Strict, rng);
+
+    for _ in 0..10 {
+        let mnemonic = context.generate("TREZOR").unwrap();
+        assert_eq!(mnemonic.as_str().split(" ").count(), 18);
+        assert!(context.validate(mnemonic.as_str()).is_ok());
+
+        let parsed = context.parse(mnemonic.as_str(), "TREZOR").unwrap();
+        assert_eq!(parsed.as_entropy(), mnemonic.as_entropy());
+        assert_eq!(parsed.as_seed().as_bytes(), mnemonic.as_seed().as_bytes());
+    }
+}
+
+#[test]
+fn context_normalization_policy() {
+    let word_list = Language::English.word_list();
+
+    let pasted = "[park, remain, person, kitchen, mule, spell, knee, armed, position, rail, grid, ankle]";
+
+    let strict = Bip39Context::new(word_list.clone(), MnemonicType::Type12Words, Normalization::Strict, XorShiftRng::from_seed([1, 2, 3, 4]));
+    assert!(strict.validate(pasted).is_err());
+    assert!(strict.parse(pasted, "").is_err());
+
+    let lenient = Bip39Context::new(word_list.clone(), MnemonicType::Type12Words, Normalization::Lenient, XorShiftRng::from_seed([1, 2, 3, 4]));
+    assert!(lenient.validate(pasted).is_ok());
+    assert_eq!(lenient.parse(pasted, "").unwrap().as_str(), "park remain person kitchen mule spell knee armed position rail grid ankle");
+}
+
+#[test]
+fn context_iteration_override() {
+    let word_list = Language::English.word_list();
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    let standard = Bip39Context::new(word_list.clone(), MnemonicType::Type12Words, Normalization::Strict, XorShiftRng::from_seed([1, 2, 3, 4]));
+    assert_eq!(standard.iterations(), None);
+
+    let mnemonic = standard.parse(test_mnemonic, "TREZOR").unwrap();
+    assert_eq!(standard.seed(&mnemonic, "TREZOR").unwrap().as_bytes(), mnemonic.as_seed().as_bytes());
+
+    let fast = Bip39Context::new(word_list.clone(), MnemonicType::Type12Words, Normalization::Strict, XorShiftRng::from_seed([1, 2, 3, 4]))
+        .with_iterations(1);
+    assert_eq!(fast.iterations(), Some(1));
+
+    let seed = fast.seed(&mnemonic, "TREZOR").unwrap();
+    assert_eq!(seed.as_bytes(), Seed::generate_with_iterations(test_mnemonic, "TREZOR", 1).unwrap().as_bytes());
+    assert_ne!(seed.as_bytes(), mnemonic.as_seed().as_bytes());
+
+    let zero = Bip39Context::new(word_list.clone(), MnemonicType::Type12Words, Normalization::Strict, XorShiftRng::from_seed([1, 2, 3, 4]))
+        .with_iterations(0);
+    assert!(zero.seed(&mnemonic, "TREZOR").is_err());
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "crypto-utils")]
+
+extern crate bip39;
+extern crate data_encoding;
+
+use data_encoding::HEXLOWER;
+use ::bip39::{sha256, sha512};
+
+#[test]
+fn sha256_known_vector() {
+    assert_eq!(HEXLOWER.encode(&sha256(b"abc")),
+               "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+}
+
+#[test]
+fn sha512_known_vector() {
+    assert_eq!(HEXLOWER.encode(&sha512(b"abc")),
+               "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f");
+}
+
+#[test]
+fn sha256_two_block_vector() {
+    assert_eq!(HEXLOWER.encode(&sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+               "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+}
+
+#[test]
+fn sha512_two_block_vector() {
+    assert_eq!(HEXLOWER.encode(&sha512(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu")),
+               "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909");
+}
@@ -1,27 +1,23 @@
+#![cfg(feature = "std")]
+
 extern crate bip39;
+extern crate rand;
 
+use std::cell::Cell;
 use std::env;
-use std::path::{Path, PathBuf};
-use ::bip39::{Mnemonic, MnemonicType};
-
-pub struct WordList {
-    pub language: String,
-    pub words: Vec<String>
-}
+use rand::{SeedableRng, XorShiftRng};
+use ::bip39::{Error, ErrorKind, Language, Mnemonic, MnemonicType, TestVector};
 
 #[test]
 fn generate_12_english() {
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
     let mnemonic_type = MnemonicType::for_word_count(12).unwrap();
 
-    let mnemonic = match Mnemonic::new(mnemonic_type, path.to_path_buf(), "") {
+    let mnemonic = match Mnemonic::new(mnemonic_type, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { println!("{:?}", path); assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
     let phrase = mnemonic.get_string();
-    let words: Vec<&str> = phrase.split(" ").into_iter().collect();
+    let words: Vec<&str> = phrase.split(" ").collect();
 
     assert!(words.len() == 12);
     assert!(words.len() == mnemonic_type.word_count());
@@ -32,17 +28,14 @@ fn generate_12_english() {
 
 #[test]
 fn generate_15_english() {
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
     let mnemonic_type = MnemonicType::for_word_count(15).unwrap();
 
-    let mnemonic= match Mnemonic::new(mnemonic_type, path.to_path_buf(), "") {
+    let mnemonic= match Mnemonic::new(mnemonic_type, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
     let phrase = mnemonic.get_string();
-    let words: Vec<&str> = phrase.split(" ").into_iter().collect();
+    let words: Vec<&str> = phrase.split(" ").collect();
 
     assert!(words.len() == 15);
     assert!(words.len() == mnemonic_type.word_count());
@@ -53,17 +46,14 @@ fn generate_15_english() {
 
 #[test]
 fn generate_18_english() {
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-    
     let mnemonic_type = MnemonicType::for_word_count(18).unwrap();
 
-    let mnemonic= match Mnemonic::new(mnemonic_type, path.to_path_buf(), "") {
+    let mnemonic= match Mnemonic::new(mnemonic_type, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
     let phrase = mnemonic.get_string();
-    let words: Vec<&str> = phrase.split(" ").into_iter().collect();
+    let words: Vec<&str> = phrase.split(" ").collect();
 
     assert!(words.len() == 18);
     assert!(words.len() == mnemonic_type.word_count());
@@ -74,18 +64,15 @@ fn generate_18_english() {
 
 #[test]
 fn generate_21_english() {
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-    
     let mnemonic_type = MnemonicType::for_word_count(21).unwrap();
 
-    let mnemonic= match Mnemonic::new(mnemonic_type, path.to_path_buf(), "") {
+    let mnemonic= match Mnemonic::new(mnemonic_type, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 
     let phrase = mnemonic.get_string();
-    let words: Vec<&str> = phrase.split(" ").into_iter().collect();
+    let words: Vec<&str> = phrase.split(" ").collect();
 
     assert!(words.len() == 21);
     assert!(words.len() == mnemonic_type.word_count());
@@ -96,18 +83,15 @@ fn generate_21_english() {
 
 #[test]
 fn generate_24_english() {
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-    
     let mnemonic_type = MnemonicType::for_word_count(24).unwrap();
 
-    let mnemonic= match Mnemonic::new(mnemonic_type, path.to_path_buf(), "") {
+    let mnemonic= match Mnemonic::new(mnemonic_type, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 
     let phrase = mnemonic.get_string();
-    let words: Vec<&str> = phrase.split(" ").into_iter().collect();
+    let words: Vec<&str> = phrase.split(" ").collect();
 
     assert!(words.len() == 24);
     assert!(words.len() == mnemonic_type.word_count());
@@ -119,15 +103,266 @@ fn generate_24_english() {
 #[test]
 #[should_panic]
 fn generate_12_english_from_invalid_entropy() {
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
-let word_list = Mnemonic::get_word_list(path).unwrap();
+    let word_list = Language::English.word_list();
 
     let mnemonic_type = MnemonicType::for_word_count(12).unwrap();
 
     // only 15 bytes
     let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A];
 
-    Mnemonic::from_entropy(entropy, mnemonic_type, &word_list, "").unwrap();
+    Mnemonic::from_entropy_with_word_list(entropy, mnemonic_type, word_list, "").unwrap();
+}
+
+#[test]
+fn generate_from_owned_entropy_matches_borrowed() {
+    let word_list = Language::English.word_list();
+
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let entropy: Vec<u8> = (0..mnemonic_type.entropy_bits() / 8).map(|i| (i * 37) as u8).collect();
+
+        let borrowed = Mnemonic::from_entropy_with_word_list(&entropy, *mnemonic_type, word_list, "").unwrap();
+        let owned = Mnemonic::from_entropy_owned(entropy.clone(), *mnemonic_type, Language::English, "").unwrap();
+
+        assert_eq!(borrowed.as_str(), owned.as_str());
+        assert_eq!(borrowed.as_entropy(), owned.as_entropy());
+
+        let slice = Mnemonic::from_entropy(&entropy, *mnemonic_type, Language::English, "").unwrap();
+
+        assert_eq!(slice.as_str(), owned.as_str());
+        assert_eq!(slice.as_seed().as_bytes(), owned.as_seed().as_bytes());
+    }
+}
+
+#[test]
+fn generate_until_matches_immediately() {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    let mnemonic = Mnemonic::generate_until(MnemonicType::Type12Words, Language::English, "", &mut rng, |_| true, 1).unwrap();
+
+    assert_eq!(mnemonic.as_str().split(" ").count(), 12);
+}
+
+#[test]
+fn generate_until_exhausts_attempts() {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    match Mnemonic::generate_until(MnemonicType::Type12Words, Language::English, "", &mut rng, |_| false, 3) {
+        Err(Error(ErrorKind::SearchExhausted(3), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn generate_until_derives_the_seed_of_the_match() {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let attempts = Cell::new(0);
+
+    // candidates don't derive a seed, so a long search stays cheap
+    let mnemonic = Mnemonic::generate_until(MnemonicType::Type12Words, Language::English, "TREZOR", &mut rng, |_| {
+        attempts.set(attempts.get() + 1);
+        attempts.get() == 5000
+    }, 5000).unwrap();
+
+    let parsed = Mnemonic::from_string(mnemonic.as_str(), Language::English, "TREZOR").unwrap();
+    assert_eq!(mnemonic.as_seed().as_bytes(), parsed.as_seed().as_bytes());
+}
+
+#[test]
+fn generate_lazy_seed_matches_eager() {
+    let word_list = Language::English.word_list();
+
+    let mut rng = XorShiftRng::from_seed([5, 6, 7, 8]);
+    let lazy = Mnemonic::new_lazy(MnemonicType::Type12Words, Language::English, "TREZOR", &mut rng).unwrap();
+
+    let mut rng = XorShiftRng::from_seed([5, 6, 7, 8]);
+    let eager = Mnemonic::generate_until(MnemonicType::Type12Words, Language::English, "TREZOR", &mut rng, |_| true, 1).unwrap();
+
+    assert_eq!(lazy.as_str(), eager.as_str());
+    assert_eq!(lazy.as_entropy(), eager.as_entropy());
+    assert_eq!(lazy.get_seed().as_hex(), eager.get_seed().as_hex());
+    assert!(Mnemonic::validate_with_word_list(lazy.as_str(), word_list).is_ok());
+}
+
+#[test]
+fn generate_12_english_from_documented_entropy() {
+    let word_list = Language::English.word_list();
+
+    // the example used throughout the crate docs
+    let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+
+    let mnemonic = Mnemonic::from_entropy_with_word_list(entropy, MnemonicType::Type12Words, word_list, "").unwrap();
+    assert_eq!(mnemonic.as_str(), "crop cash unable insane eight faith inflict route frame loud box vibrant");
+    assert_eq!(mnemonic.as_entropy(), &entropy[..]);
+    assert_eq!(mnemonic.as_seed().as_hex(), "BEBAD92D1759AEE7895747ABE05D7B52CAABE9ED4F36E44C29DECF0A2A12C1BD283F52EB1988C93E9B9A7E5482A64584AC7DF7589F2C8B5934FE6D2E2F08922C");
+
+    let mnemonic = Mnemonic::from_entropy_with_word_list(entropy, MnemonicType::Type12Words, word_list, "TREZOR").unwrap();
+    assert_eq!(mnemonic.as_seed().as_hex(), "9027D927A9FD3ADA044302D52E1B997219C6DA908C9080D74E0188BA4F99AA7FF24EBE04C0C97D11A08191C5A49E2C89B8C38BB73404B7A08F090353354A32AD");
+}
+
+#[test]
+fn generate_from_entropy_with_mismatched_language() {
+    let word_list = Language::English.word_list();
+
+    let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+
+    let mnemonic = Mnemonic::from_entropy_checked(entropy, MnemonicType::Type12Words, word_list, "English", "").unwrap();
+    assert_eq!(mnemonic.as_str(), "crop cash unable insane eight faith inflict route frame loud box vibrant");
+
+    match Mnemonic::from_entropy_checked(entropy, MnemonicType::Type12Words, word_list, "japanese", "") {
+        Err(Error(ErrorKind::LanguageMismatch(ref expected, ref found), _)) => {
+            assert_eq!(expected, "japanese");
+            assert_eq!(found, "english");
+        },
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn generate_mixed_entropy_is_deterministic() {
+    let word_list = Language::English.word_list();
+
+    let mut rng = XorShiftRng::from_seed([9, 10, 11, 12]);
+    let system = Mnemonic::new_lazy(MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+
+    // user bytes of the right length are mixed in directly
+    let user_bytes: Vec<u8> = (0..16).map(|i| i * 17).collect();
+    let mut rng = XorShiftRng::from_seed([9, 10, 11, 12]);
+    let mixed = Mnemonic::new_mixed_entropy(&user_bytes, MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+
+    let expected: Vec<u8> = system.as_entropy().iter().zip(&user_bytes).map(|(a, b)| a ^ b).collect();
+    assert_eq!(mixed.as_entropy(), &expected[..]);
+    assert!(Mnemonic::validate_with_word_list(mixed.as_str(), word_list).is_ok());
+
+    // anything else is hashed first, the same inputs always give the same phrase
+    let dice_rolls = b"3162445512366142536163423315";
+    let mut rng = XorShiftRng::from_seed([9, 10, 11, 12]);
+    let first = Mnemonic::new_mixed_entropy(dice_rolls, MnemonicType::Type24Words, Language::English, "", &mut rng).unwrap();
+    let mut rng = XorShiftRng::from_seed([9, 10, 11, 12]);
+    let second = Mnemonic::new_mixed_entropy(dice_rolls, MnemonicType::Type24Words, Language::English, "", &mut rng).unwrap();
+
+    assert_eq!(first.as_str(), second.as_str());
+    assert_eq!(first.as_entropy().len(), 32);
+
+    let mut rng = XorShiftRng::from_seed([9, 10, 11, 12]);
+    let unmixed = Mnemonic::new_lazy(MnemonicType::Type24Words, Language::English, "", &mut rng).unwrap();
+    assert_ne!(first.as_entropy(), unmixed.as_entropy());
+}
+
+#[test]
+fn generate_with_seeded_rng_is_reproducible() {
+    let mut rng = XorShiftRng::from_seed([13, 14, 15, 16]);
+    let first = Mnemonic::new_with_rng(MnemonicType::Type24Words, Language::English, "TREZOR", &mut rng).unwrap();
+
+    let mut rng = XorShiftRng::from_seed([13, 14, 15, 16]);
+    let second = Mnemonic::new_with_rng(MnemonicType::Type24Words, Language::English, "TREZOR", &mut rng).unwrap();
+
+    assert_eq!(first.as_str(), second.as_str());
+    assert_eq!(first.as_entropy(), second.as_entropy());
+    assert_eq!(first.get_seed().as_hex(), second.get_seed().as_hex());
+    assert_eq!(first.word_count(), 24);
+
+    // the same rng state gives the same entropy as the lazy constructor
+    let mut rng = XorShiftRng::from_seed([13, 14, 15, 16]);
+    let lazy = Mnemonic::new_lazy(MnemonicType::Type24Words, Language::English, "TREZOR", &mut rng).unwrap();
+
+    assert_eq!(first.as_str(), lazy.as_str());
+}
+
+#[test]
+fn generate_from_dice_rolls() {
+    let rolls = [3, 1, 6, 2, 4, 4, 5, 5, 1, 2, 3, 6, 6, 1, 4, 2, 5, 3, 6, 1, 6, 3, 4, 2, 3,
+                 3, 1, 5, 2, 4, 6, 6, 1, 3, 5, 2, 4, 1, 1, 6, 2, 5, 3, 4, 4, 6, 5, 1, 2, 3];
+
+    let mnemonic = Mnemonic::from_dice_rolls(&rolls, MnemonicType::Type12Words, Language::English, "").unwrap();
+    assert_eq!(mnemonic.as_str(), "drama worry detail future ostrich ship crack void crime notice exotic street");
+    assert_eq!(mnemonic.as_entropy(), &[0x42, 0x3F, 0xB8, 0xF1, 0x2F, 0x49, 0xCF, 0x8C, 0x0C, 0x77, 0xAC, 0x33, 0x92, 0xDD, 0x40, 0x6B][..]);
+
+    match Mnemonic::from_dice_rolls(&rolls[..49], MnemonicType::Type12Words, Language::English, "") {
+        Err(Error(ErrorKind::NotEnoughDiceRolls(49, 50), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+
+    let required = [(MnemonicType::Type15Words, 62), (MnemonicType::Type18Words, 75),
+                    (MnemonicType::Type21Words, 87), (MnemonicType::Type24Words, 100)];
+    for &(mnemonic_type, count) in &required {
+        let rolls = vec![6u8; count];
+        assert!(Mnemonic::from_dice_rolls(&rolls, mnemonic_type, Language::English, "").is_ok());
+        match Mnemonic::from_dice_rolls(&rolls[1..], mnemonic_type, Language::English, "") {
+            Err(Error(ErrorKind::NotEnoughDiceRolls(found, needed), _)) => assert_eq!((found, needed), (count - 1, count)),
+            other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+        }
+    }
+
+    let mut invalid = rolls;
+    invalid[10] = 7;
+    match Mnemonic::from_dice_rolls(&invalid, MnemonicType::Type12Words, Language::English, "") {
+        Err(Error(ErrorKind::InvalidDiceRoll(7), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn generate_from_coin_flips() {
+    // the bits of the entropy used throughout the crate docs
+    let bits = "00110011111001000110101110110001001110100111010001101110101001000001110011011101111001000101110010010000100001000110101001111001";
+    let flips: Vec<bool> = bits.chars().map(|c| c == '1').collect();
+
+    let mnemonic = Mnemonic::from_coin_flips(&flips, MnemonicType::Type12Words, Language::English, "").unwrap();
+    assert_eq!(mnemonic.as_str(), "crop cash unable insane eight faith inflict route frame loud box vibrant");
+
+    for count in &[0, 127, 129, 160] {
+        match Mnemonic::from_coin_flips(&vec![false; *count], MnemonicType::Type12Words, Language::English, "") {
+            Err(Error(ErrorKind::InvalidEntropyLength(bits, _), _)) => assert_eq!(bits, *count),
+            other => panic!("unexpected result for {}: {:?}", count, other.map(|m| m.get_string()))
+        }
+    }
+}
+
+#[test]
+fn generate_from_entropy_rejects_non_standard_lengths() {
+    // 96 bits, and 136 bits as the closest byte length to 129 bits
+    for bytes in &[12, 17, 0, 33] {
+        let entropy = vec![0u8; *bytes];
+
+        for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type24Words] {
+            match Mnemonic::from_entropy(&entropy, *mnemonic_type, Language::English, "") {
+                Err(Error(ErrorKind::InvalidEntropyBits(bits), _)) => assert_eq!(bits, *bytes * 8),
+                other => panic!("unexpected result for {} bytes: {:?}", bytes, other.map(|m| m.get_string()))
+            }
+        }
+    }
+
+    // a standard length is still checked against the requested type
+    match Mnemonic::from_entropy(&[0u8; 20], MnemonicType::Type12Words, Language::English, "") {
+        Err(Error(ErrorKind::InvalidEntropyLength(160, MnemonicType::Type12Words), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn generate_from_entropy_matches_parsed_phrase() {
+    let mut path = env::current_dir().unwrap();
+    path.push("tests/vectors/english.json");
+
+    for vector in TestVector::load(path).unwrap() {
+        let mnemonic_type = MnemonicType::for_key_size(vector.entropy.len() * 4).unwrap();
+
+        let generated = Mnemonic::from_entropy_hex(&vector.entropy.to_uppercase(), mnemonic_type, Language::English, &*vector.passphrase).unwrap();
+        let parsed = Mnemonic::from_string(&*vector.mnemonic, Language::English, &*vector.passphrase).unwrap();
+
+        assert_eq!(generated.as_str(), vector.mnemonic);
+        assert_eq!(generated.get_entropy_hex_lower(), vector.entropy.to_lowercase());
+        assert_eq!(generated.as_seed().get_hex_lower(), vector.seed.to_lowercase());
+
+        assert_eq!(generated.as_str(), parsed.as_str());
+        assert_eq!(generated.as_entropy(), parsed.as_entropy());
+        assert_eq!(generated.as_seed().as_bytes(), parsed.as_seed().as_bytes());
+    }
+
+    // the Japanese separator is used when building the phrase
+    let japanese = Mnemonic::from_entropy(&[0u8; 16], MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+    let parsed = Mnemonic::from_string(japanese.as_str(), Language::Japanese, "").unwrap();
+    assert_eq!(japanese.as_str().split('\u{3000}').count(), 12);
+    assert_eq!(japanese.as_seed().as_bytes(), parsed.as_seed().as_bytes());
 }
@@ -0,0 +1,105 @@
+extern crate bip39;
+extern crate unicode_normalization;
+
+use unicode_normalization::UnicodeNormalization;
+use ::bip39::{Language, Mnemonic, MnemonicType, Seed};
+
+// from the Japanese BIP39 test vectors at https://github.com/bip32JP/bip32JP.github.io,
+// (entropy, mnemonic, seed), all using the same passphrase
+static PASSPHRASE: &str = "㍍ガバヴァぱばぐゞちぢ十人十色";
+
+static VECTORS: &[(&str, &str, &str)] = &[
+    ("00000000000000000000000000000000",
+     "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら",
+     "A262D6FB6122ECF45BE09C50492B31F92E9BEB7D9A845987A02CEFDA57A15F9C467A17872029A9E92299B5CBDF306E3A0EE620245CBD508959B6CB7CA637BD55"),
+    ("7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F",
+     "そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れきだい　ほんやく　わかめ",
+     "AEE025CBE6CA256862F889E48110A6A382365142F7D16F2B9545285B3AF64E542143A577E9C144E101A6BDCA18F8D97EC3366EBF5B088B1C1AF9BC31346E60D9"),
+    ("15DA872C95A13DD738FBF50E427583AD61F18FD99F628C417A61CF8343C90419",
+     "うちゅう　ふそく　ひしょ　がちょう　うけもつ　めいそう　みかん　そざい　いばる　うけとる　さんま　さこつ　おうさま　ぱんつ　しひょう　めした　たはつ　いちぶ　つうじょう　てさぎょう　きつね　みすえる　いりぐち　かめれおん",
+     "346B7321D8C04F6F37B49FDF062A2FDDC8E1BF8F1D33171B65074531EC546D1D3469974BECCB1A09263440FC92E1042580A557FDCE314E27EE4EABB25FA5E5FE")
+];
+
+#[test]
+fn japanese_phrases_match_vectors() {
+    for &(entropy, phrase, _) in VECTORS {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let mnemonic = Mnemonic::from_entropy_hex(entropy, mnemonic_type, Language::Japanese, PASSPHRASE).unwrap();
+
+        // the word list is stored decomposed, the vectors are written out composed. Normalizing
+        // the whole phrase would also turn the ideographic spaces into ASCII ones, so only the
+        // words are normalized here
+        let words: Vec<String> = phrase.split('\u{3000}').map(|word| word.nfkd().collect()).collect();
+
+        assert_eq!(mnemonic.as_str(), words.join("\u{3000}"));
+        assert_eq!(mnemonic.as_str().split('\u{3000}').count(), mnemonic_type.word_count());
+        assert!(!mnemonic.as_str().contains(' '));
+    }
+}
+
+#[test]
+fn japanese_phrases_parse() {
+    for &(entropy, _, _) in VECTORS {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let generated = Mnemonic::from_entropy_hex(entropy, mnemonic_type, Language::Japanese, "").unwrap();
+
+        let parsed = Mnemonic::from_string(generated.get_string(), Language::Japanese, "".to_owned()).unwrap();
+
+        assert_eq!(parsed.as_entropy(), generated.as_entropy());
+        assert_eq!(MnemonicType::for_phrase(parsed.as_str()).unwrap().word_count(), mnemonic_type.word_count());
+    }
+}
+
+#[test]
+fn japanese_seeds_match_vectors() {
+    for &(entropy, phrase, seed) in VECTORS {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let mnemonic = Mnemonic::from_entropy_hex(entropy, mnemonic_type, Language::Japanese, PASSPHRASE).unwrap();
+
+        assert_eq!(mnemonic.get_seed().as_hex(), seed);
+        assert_eq!(Seed::bip39(mnemonic.as_str(), PASSPHRASE).as_hex(), seed);
+        assert_eq!(Seed::bip39(phrase, PASSPHRASE).as_hex(), seed);
+    }
+}
+
+#[test]
+fn japanese_passphrase_is_normalized() {
+    for &(entropy, _, seed) in VECTORS {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let generated = Mnemonic::from_entropy_hex(entropy, mnemonic_type, Language::Japanese, "").unwrap();
+
+        // the phrase with ASCII spaces is already in NFKD form, so only the passphrase needs
+        // normalizing for the seed to match
+        let phrase = generated.as_str().replace('\u{3000}', " ");
+        let mnemonic = Mnemonic::from_string(&*phrase, Language::Japanese, PASSPHRASE).unwrap();
+
+        assert_eq!(mnemonic.get_seed().as_hex(), seed);
+    }
+}
+
+#[test]
+fn japanese_phrase_with_extra_whitespace_is_stored_with_ideographic_spaces() {
+    let generated = Mnemonic::from_entropy_hex(VECTORS[0].0, MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+
+    let spaced = format!("  {}\n", generated.as_str().replace('\u{3000}', " \u{3000} "));
+    let mnemonic = Mnemonic::from_string(spaced, Language::Japanese, "".to_owned()).unwrap();
+
+    assert_eq!(mnemonic.as_str(), generated.as_str());
+    assert_eq!(mnemonic.get_seed().as_hex(), generated.get_seed().as_hex());
+}
+
+#[test]
+fn composed_japanese_phrases_parse() {
+    for &(entropy, phrase, seed) in VECTORS {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let generated = Mnemonic::from_entropy_hex(entropy, mnemonic_type, Language::Japanese, PASSPHRASE).unwrap();
+
+        // the vectors are written out composed, the word list is decomposed
+        assert!(Mnemonic::validate(phrase, Language::Japanese).is_ok());
+
+        let mnemonic = Mnemonic::from_string(phrase, Language::Japanese, PASSPHRASE).unwrap();
+
+        assert_eq!(mnemonic.as_str(), generated.as_str());
+        assert_eq!(mnemonic.get_seed().as_hex(), seed);
+    }
+}
@@ -0,0 +1,256 @@
+extern crate bip39;
+
+use ::bip39::{Language, Mnemonic, MnemonicType};
+
+#[test]
+fn detect_finds_the_language_of_a_phrase() {
+    let entropy = [0x7fu8; 16];
+
+    for language in &[Language::English, Language::Japanese] {
+        let mnemonic = Mnemonic::from_entropy(&entropy, MnemonicType::Type12Words, *language, "").unwrap();
+
+        assert_eq!(Language::detect(mnemonic.as_str()), Some(*language));
+    }
+
+    // composed Japanese input is matched against the decomposed word list
+    assert_eq!(Language::detect("がちょう　うけもつ"), Some(Language::Japanese));
+
+    // a French word among English ones
+    assert_eq!(Language::detect("park remain personne kitchen"), None);
+    assert_eq!(Language::detect(""), None);
+    assert_eq!(Language::detect(" \u{3000} "), None);
+}
+
+#[test]
+fn contains_all_matches_the_word_list() {
+    let mnemonic = Mnemonic::from_entropy(&[0x7fu8; 16], MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+    let words: Vec<&str> = mnemonic.words().collect();
+
+    assert!(Language::Japanese.contains_all(&words));
+    assert!(!Language::English.contains_all(&words));
+    assert_eq!(Language::English.contains_all(&["park", "remain"]),
+               Language::English.word_list().contains_all(&["park", "remain"]));
+    assert!(Language::English.contains_all(&[]));
+}
+
+#[test]
+fn to_language_preserves_entropy() {
+    let entropy = [0x15u8, 0xda, 0x87, 0x2c, 0x95, 0xa1, 0x3d, 0xd7, 0x38, 0xfb, 0xf5, 0x0e, 0x42, 0x75, 0x83, 0xad,
+                   0x61, 0xf1, 0x8f, 0xd9, 0x9f, 0x62, 0x8c, 0x41, 0x7a, 0x61, 0xcf, 0x83, 0x43, 0xc9, 0x04, 0x19];
+
+    let english = Mnemonic::from_entropy(&entropy, MnemonicType::Type24Words, Language::English, "TREZOR").unwrap();
+
+    let japanese = english.to_language(Language::Japanese, "TREZOR").unwrap();
+    assert_eq!(japanese.as_entropy(), &entropy[..]);
+    assert_eq!(japanese.language(), Some(Language::Japanese));
+
+    // the seed is the standard one for the Japanese phrase, not a copy of the English seed
+    let direct = Mnemonic::from_entropy(&entropy, MnemonicType::Type24Words, Language::Japanese, "TREZOR").unwrap();
+    assert_eq!(japanese.as_str(), direct.as_str());
+    assert_eq!(japanese.as_seed().as_bytes(), direct.as_seed().as_bytes());
+    assert!(japanese.as_seed().as_bytes() != english.as_seed().as_bytes());
+
+    let back = japanese.to_language(Language::English, "TREZOR").unwrap();
+    assert_eq!(back.as_entropy(), english.as_entropy());
+    assert_eq!(back.as_str(), english.as_str());
+}
+
+// (entropy, mnemonic, seed) with the passphrase "TREZOR", seeds computed independently with
+// PBKDF2-HMAC-SHA512 over the NFKD normalized phrase
+#[cfg(feature = "lang-spanish")]
+static SPANISH_VECTORS: &[(&str, &str, &str)] = &[
+    ("00000000000000000000000000000000",
+     "ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco abierto",
+     "29A2EE16DE47D07025DE37E7D9C596869439F9BCD26A702D2BAE64DB2BF0F68383841C5444B5B3BD39DD720D2EBE59969E110E5955C8E6D32C6C3294FD87439B"),
+    ("7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F",
+     "ligero vista talar yogur venta queso yacer trozo ligero vista talar yogur venta queso vivero",
+     "913617DC21198A8877D974CFA6325BDD0E70CC2E362284B9DF0902472693899FF1CC84E6E004113FD4A6258785485B888EC1479EEE2E8336B2843CE45E2F18A8"),
+    ("15DA872C95A13DD738FBF50E427583AD61F18FD99F628C417A61CF8343C90419",
+     "atajo secta rito carga asalto torpedo teléfono libro anual asado gallo flauta boa rescate gratis toser melón ameno náusea obvio clínica tender apuro caudal",
+     "9F99AE125B87B67703D85562F90A95C2F72066A3BC39E7B4578C7F79856949F3FD4ACF976743B9BE9CAC0E2E1063E7BC86CA8DDFFCC2B67EFCC8B31D69ADC067")
+];
+
+#[cfg(feature = "lang-italian")]
+static ITALIAN_VECTORS: &[(&str, &str, &str)] = &[
+    ("00000000000000000000000000000000",
+     "abaco abaco abaco abaco abaco abaco abaco abaco abaco abaco abaco abete",
+     "D2AE4BBD4EFC4ABA345B66DC2BFA4EA280D85810945BA4E100707694D5731C5A42AC0D0308BA9AD176966879328F1AA014FBCBEB46D671D9475C38254BF1EEB7"),
+    ("7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F",
+     "mimosa vita sussurro zinco vero saltare zattera ulisse mimosa vita sussurro zinco vero saltare vivido",
+     "2A7351F252234B95340B8A1E61594C5BE06BBC4461CBFA2AC97883255DE9A95E659094E18D51DF22B72DBC63898A9464DE0778526D4E53F38B76A836B7CD4D6B"),
+    ("15DA872C95A13DD738FBF50E427583AD61F18FD99F628C417A61CF8343C90419",
+     "autista sogno serio chimera assurdo treccia tecnico microbo apertura assoluto grado gamma bordo scusare impiego trillo nuvola anarchia palude pettine criceto tendone ardito cittadino",
+     "457DF84D1553FDED17969444F8CEE1CCCE9CF3306CD23D79F8C0C9025960688ABCA3E413EDED27776DE38208393EFDA567078809D5F67569A10E5FF0D9D7D6C2")
+];
+
+#[cfg(any(feature = "lang-spanish", feature = "lang-italian"))]
+fn check_vectors(vectors: &[(&str, &str, &str)], language: Language) {
+    for &(entropy, phrase, seed) in vectors {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let mnemonic = Mnemonic::from_entropy_hex(entropy, mnemonic_type, language, "TREZOR").unwrap();
+
+        assert_eq!(mnemonic.as_str(), phrase);
+        assert_eq!(mnemonic.as_seed().as_hex(), seed);
+
+        // words are joined with an ASCII space, and any whitespace is accepted when parsing
+        assert_eq!(language.separator(), " ");
+        let spaced = phrase.replace(' ', " \u{3000}\t");
+        let parsed = Mnemonic::from_string(&*spaced, language, "TREZOR").unwrap();
+        assert_eq!(parsed.as_str(), phrase);
+        assert_eq!(parsed.as_seed().as_hex(), seed);
+
+        assert_eq!(Language::detect(phrase), Some(language));
+    }
+}
+
+#[cfg(feature = "lang-spanish")]
+#[test]
+fn spanish_phrases_match_vectors() {
+    check_vectors(SPANISH_VECTORS, Language::Spanish);
+}
+
+#[cfg(feature = "lang-spanish")]
+#[test]
+fn composed_spanish_phrases_parse() {
+    // "ábaco" written with a precomposed "á", the word list has it decomposed
+    let composed = "\u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco \u{e1}baco abierto";
+    let (entropy, phrase, _) = SPANISH_VECTORS[0];
+
+    assert!(Mnemonic::validate(composed, Language::Spanish).is_ok());
+    assert!(Mnemonic::validate_detailed(composed, Language::Spanish).is_ok());
+
+    let mnemonic = Mnemonic::from_string(composed, Language::Spanish, "TREZOR").unwrap();
+    assert_eq!(mnemonic.as_str(), phrase);
+    assert_eq!(mnemonic.get_entropy_hex(), entropy);
+}
+
+#[test]
+fn composed_prefixes_expand() {
+    // "ぎじかがく" is 4 characters into its prefix only once the voicing marks are composed
+    let composed = "\u{304e}\u{3058}\u{304b}\u{304c}";
+    let decomposed = "\u{304d}\u{3099}\u{3057}\u{3099}\u{304b}\u{304b}\u{3099}";
+    let expected = Mnemonic::from_entropy_hex("320640C8190320640C8190320640C800", MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+
+    for prefix in &[composed, decomposed] {
+        let mut prefixes = vec![*prefix; 11];
+        prefixes.push("あおぞら");
+
+        let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::Japanese, "").unwrap();
+        assert_eq!(mnemonic.as_str(), expected.as_str());
+    }
+}
+
+#[cfg(feature = "lang-spanish")]
+#[test]
+fn composed_spanish_prefixes_expand() {
+    let (entropy, phrase, _) = SPANISH_VECTORS[0];
+    let mut prefixes = vec!["\u{e1}bac"; 11];
+    prefixes.push("abie");
+
+    let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::Spanish, "TREZOR").unwrap();
+    assert_eq!(mnemonic.as_str(), phrase);
+    assert_eq!(mnemonic.get_entropy_hex(), entropy);
+}
+
+#[cfg(feature = "lang-italian")]
+#[test]
+fn italian_phrases_match_vectors() {
+    check_vectors(ITALIAN_VECTORS, Language::Italian);
+}
+
+// every language compiled in with the enabled features
+static LANGUAGES: &[Language] = &[
+    Language::English,
+    Language::Japanese,
+    #[cfg(feature = "lang-spanish")]
+    Language::Spanish,
+    #[cfg(feature = "lang-french")]
+    Language::French,
+    #[cfg(feature = "lang-italian")]
+    Language::Italian,
+    #[cfg(feature = "lang-czech")]
+    Language::Czech,
+    #[cfg(feature = "lang-korean")]
+    Language::Korean,
+    #[cfg(feature = "lang-chinese-simplified")]
+    Language::ChineseSimplified,
+    #[cfg(feature = "lang-chinese-traditional")]
+    Language::ChineseTraditional,
+];
+
+#[test]
+fn embedded_word_lists_are_complete() {
+    for language in LANGUAGES {
+        let word_list = language.word_list();
+
+        assert_eq!(word_list.words.len(), 2048);
+        assert_eq!(word_list.as_language(), Some(*language));
+
+        // prefixes are counted the same way validate_structure() counts them
+        assert!(word_list.validate_structure().is_ok());
+        assert!(word_list.unique_prefixes().iter().all(|length| *length <= 4));
+
+        let entropy = [0x7fu8; 16];
+        let mnemonic = Mnemonic::from_entropy(&entropy, MnemonicType::Type12Words, *language, "").unwrap();
+        assert_eq!(Mnemonic::from_string(mnemonic.as_str(), *language, "").unwrap().as_entropy(), &entropy[..]);
+    }
+}
+
+#[cfg(all(feature = "lang-chinese-simplified", feature = "lang-chinese-traditional"))]
+#[test]
+fn chinese_scripts_convert_without_changing_entropy() {
+    let entropy = "15DA872C95A13DD738FBF50E427583AD61F18FD99F628C417A61CF8343C90419";
+
+    let simplified = Mnemonic::from_entropy_hex(entropy, MnemonicType::Type24Words, Language::ChineseSimplified, "TREZOR").unwrap();
+    assert_eq!(simplified.as_str(), "情 韩 貌 科 此 飘 杰 横 前 命 普 混 干 肩 欢 烷 愈 当 朗 柱 约 叙 与 温");
+    assert_eq!(simplified.as_seed().as_hex(), "01204593C1558EB4701C18C476C5FA27CD8076BD218A11D848A87417A7012B02404320B132F891C8EA9108A366A6AB383CE2958D9A426D1474A1FBDADE6E9CE9");
+
+    let traditional = simplified.to_language(Language::ChineseTraditional, "TREZOR").unwrap();
+    assert_eq!(traditional.as_str(), "情 韓 貌 科 此 飄 傑 橫 前 命 普 混 幹 肩 歡 烷 愈 當 朗 柱 約 敘 與 溫");
+    assert_eq!(traditional.as_seed().as_hex(), "94FCAD39535A29EF0B6024FF78C18933F721C285651D52D13E026AD91AE7608491D579DA0C7DACE3EA5B17AEB16D9C9E1AD8B9647C9BF3968441D775C15AAF51");
+
+    // the lists share positions, so the entropy and word indices carry over unchanged
+    assert!(traditional.same_entropy(&simplified));
+    assert_eq!(traditional.to_indices(), simplified.to_indices());
+    assert_eq!(traditional.to_language(Language::ChineseSimplified, "TREZOR").unwrap().as_str(), simplified.as_str());
+
+    // Chinese phrases are joined with an ASCII space
+    assert_eq!(Language::ChineseSimplified.separator(), " ");
+    assert_eq!(Language::ChineseTraditional.separator(), " ");
+
+    assert_eq!(Language::detect(traditional.as_str()), Some(Language::ChineseTraditional));
+}
+
+#[test]
+fn abbreviated_round_trip_every_word() {
+    for language in LANGUAGES {
+        let words = &language.word_list().words;
+
+        // 24 word phrases over the whole list, the checksum word replaces every 24th word, so each
+        // phrase starts on the word the previous one's checksum replaced
+        for start in (0..words.len()).step_by(23) {
+            let phrase: Vec<&str> = (start..start + 24).map(|index| words[index % words.len()].as_str()).collect();
+            let mnemonic = Mnemonic::regenerate_checksum(phrase.join(language.separator()), *language, String::new()).unwrap();
+
+            let abbreviated = mnemonic.to_abbreviated();
+            let expanded = Mnemonic::from_abbreviated(&abbreviated, *language, "").unwrap();
+
+            assert_eq!(expanded.as_str(), mnemonic.as_str(), "{:?}", language);
+            assert_eq!(expanded.as_entropy(), mnemonic.as_entropy());
+        }
+    }
+}
+
+#[cfg(feature = "lang-korean")]
+#[test]
+fn abbreviated_korean_round_trip() {
+    let english = Mnemonic::from_entropy(&[0x7fu8; 16], MnemonicType::Type12Words, Language::English, "").unwrap();
+    let korean = english.to_language(Language::Korean, "").unwrap();
+
+    // Hangul syllables are decomposed in the phrase but counted whole in the abbreviation
+    let abbreviated = korean.to_abbreviated();
+    let expanded = Mnemonic::from_abbreviated(&abbreviated, Language::Korean, "").unwrap();
+
+    assert_eq!(expanded.as_str(), korean.as_str());
+    assert!(expanded.same_entropy(&english));
+}
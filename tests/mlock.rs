@@ -0,0 +1,42 @@
+#![cfg(feature = "mlock")]
+
+extern crate bip39;
+
+use ::bip39::{Language, Mnemonic};
+
+#[test]
+fn locked_seed_matches_reference() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+    let seed = mnemonic.get_seed();
+
+    // locking is best effort, so don't depend on it succeeding in the test environment
+    println!("seed memory locked: {}", seed.is_memory_locked());
+
+    assert_eq!(seed.get_hex_lower(),
+               "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+    assert_eq!(seed.as_bytes(), mnemonic.as_seed().as_bytes());
+
+    // the hex is served from its own locked copy, not a plain String
+    assert_eq!(seed.as_hex(), seed.get_hex_lower().to_uppercase());
+    assert_eq!(seed.clone().as_hex(), seed.as_hex());
+}
+
+#[test]
+fn dropping_a_copy_keeps_the_seed() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+    let seed = mnemonic.get_seed();
+
+    // each copy has pages of its own, clearing one on drop doesn't touch the other
+    let locked = seed.is_memory_locked();
+    drop(seed.clone());
+
+    assert_eq!(seed.is_memory_locked(), locked);
+    assert_eq!(seed.as_bytes(), mnemonic.as_seed().as_bytes());
+    assert_eq!(seed.as_hex(), mnemonic.as_seed().as_hex());
+}
@@ -0,0 +1,531 @@
+extern crate bip39;
+#[cfg(feature = "std")]
+extern crate rand;
+
+#[cfg(feature = "std")]
+use rand::{SeedableRng, XorShiftRng};
+use ::bip39::{entropy_to_indices, indices_to_entropy, Error, ErrorKind, Language, Mnemonic, MnemonicType, WordList};
+
+#[test]
+fn masked_entropy_hex() {
+    let entropy = "33E46BB13A746EA41CDDE45C90846A79";
+    let mnemonic = Mnemonic::from_entropy_hex(entropy, MnemonicType::Type12Words, Language::English, "").unwrap();
+
+    let masked = mnemonic.masked_entropy_hex(8);
+    assert_eq!(masked, "33E46BB1…");
+    assert_eq!(masked.chars().count(), 9);
+    assert!(entropy.starts_with(masked.trim_end_matches('…')));
+
+    assert_eq!(mnemonic.masked_entropy_hex(0), "…");
+    assert_eq!(mnemonic.masked_entropy_hex(32), entropy);
+    assert_eq!(mnemonic.masked_entropy_hex(100), entropy);
+}
+
+#[test]
+fn entropy_indices_round_trip() {
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let entropy: Vec<u8> = (0..mnemonic_type.entropy_bits() / 8).map(|i| (i * 91 + 7) as u8).collect();
+
+        let indices = entropy_to_indices(&entropy, *mnemonic_type).unwrap();
+        assert_eq!(indices.len(), mnemonic_type.word_count());
+
+        let decoded = indices_to_entropy(&indices, *mnemonic_type).unwrap();
+        assert_eq!(entropy, decoded);
+    }
+}
+
+#[test]
+fn indices_to_entropy_rejects_bad_input() {
+    let indices = entropy_to_indices(&[0u8; 16], MnemonicType::Type12Words).unwrap();
+
+    let mut corrupted = indices.clone();
+    corrupted[11] ^= 1;
+    assert!(indices_to_entropy(&corrupted, MnemonicType::Type12Words).is_err());
+
+    let mut out_of_range = indices.clone();
+    out_of_range[0] = 2048;
+    assert!(indices_to_entropy(&out_of_range, MnemonicType::Type12Words).is_err());
+
+    match indices_to_entropy(&indices[..11], MnemonicType::Type12Words) {
+        Err(Error(ErrorKind::InvalidWordCount(11), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn entropy_array() {
+    let word_list = Language::English.word_list();
+
+    let twelve = Mnemonic::from_entropy_with_word_list(&[0x7f; 16], MnemonicType::Type12Words, word_list, "").unwrap();
+    let twenty_four = Mnemonic::from_entropy_with_word_list(&[0x7f; 32], MnemonicType::Type24Words, word_list, "").unwrap();
+
+    assert_eq!(twelve.entropy_array::<16>(), Some([0x7f; 16]));
+    assert_eq!(twenty_four.entropy_array::<32>(), Some([0x7f; 32]));
+
+    assert_eq!(twelve.entropy_array::<32>(), None);
+    assert_eq!(twenty_four.entropy_array::<16>(), None);
+}
+
+#[test]
+fn try_split_12_12() {
+    let word_list = Language::English.word_list();
+
+    let splittable = "arctic trouble bag payment pig drill cliff smile square dragon three common above carpet camp luggage climb desk rubber unfair angle unable tattoo inspire";
+    let mnemonic = Mnemonic::from_string_with_word_list(splittable, word_list, "").unwrap();
+
+    let (first, second) = mnemonic.try_split_12_12("TREZOR").unwrap();
+    assert_eq!(first.as_str(), "arctic trouble bag payment pig drill cliff smile square dragon three common");
+    assert_eq!(second.as_str(), "above carpet camp luggage climb desk rubber unfair angle unable tattoo inspire");
+    assert_eq!(first.as_seed().as_bytes(), Mnemonic::from_string(first.as_str(), Language::English, "TREZOR").unwrap().as_seed().as_bytes());
+
+    let unsplittable = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword world";
+    let mnemonic = Mnemonic::from_string_with_word_list(unsplittable, word_list, "").unwrap();
+    assert!(mnemonic.try_split_12_12("").is_none());
+
+    let twelve = Mnemonic::from_string_with_word_list("park remain person kitchen mule spell knee armed position rail grid ankle", word_list, "").unwrap();
+    assert!(twelve.try_split_12_12("").is_none());
+}
+
+#[test]
+fn iter_with_indices() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+
+    let items: Vec<(usize, u16, &str)> = mnemonic.iter_with_indices().collect();
+
+    assert_eq!(items.len(), 12);
+    assert_eq!(items[0], (0, 1282, "park"));
+    assert_eq!(items[11], (11, 73, "ankle"));
+
+    for (position, index, word) in items {
+        assert_eq!(word_list.words[index as usize], word);
+        assert_eq!(test_mnemonic.split(" ").nth(position), Some(word));
+    }
+}
+
+#[test]
+fn rebuild_canonical_from_prefixes() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+
+    let parsed = Mnemonic::from_prefixes(&prefixes, Language::English, "TREZOR").unwrap();
+    let canonical = parsed.rebuild_canonical();
+    let expected = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+
+    assert_eq!(canonical.as_str(), test_mnemonic);
+    assert_eq!(canonical.as_entropy(), expected.as_entropy());
+    assert_eq!(canonical.as_seed().as_bytes(), expected.as_seed().as_bytes());
+}
+
+#[test]
+fn rebuild_canonical_uses_the_language_separator() {
+    let japanese = Mnemonic::from_entropy(&[0u8; 16], MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+    let spaced = japanese.as_str().replace('\u{3000}', " ");
+
+    let parsed = Mnemonic::from_string(&*spaced, Language::Japanese, "").unwrap();
+    let canonical = parsed.rebuild_canonical();
+
+    assert_eq!(canonical.as_str(), japanese.as_str());
+    assert_eq!(canonical.as_str().matches('\u{3000}').count(), 11);
+}
+
+#[test]
+fn normalize_phrase_is_idempotent() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mut mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+    let seed = mnemonic.get_seed();
+
+    mnemonic.normalize_phrase();
+    assert_eq!(mnemonic.as_str(), test_mnemonic);
+    assert_eq!(mnemonic.as_seed().as_bytes(), seed.as_bytes());
+
+    mnemonic.normalize_phrase();
+    assert_eq!(mnemonic.as_str(), test_mnemonic);
+    assert_eq!(mnemonic.as_seed().as_bytes(), seed.as_bytes());
+}
+
+#[test]
+fn checksum_matches_indices() {
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let entropy: Vec<u8> = (0..mnemonic_type.entropy_bits() / 8).map(|i| (i * 37 + 3) as u8).collect();
+
+        let indices = entropy_to_indices(&entropy, *mnemonic_type).unwrap();
+        assert!(Mnemonic::checksum_matches(&indices, *mnemonic_type));
+
+        // flipping any single checksum bit must be caught
+        let last = indices.len() - 1;
+        for bit in 0..mnemonic_type.checksum_bits() {
+            let mut flipped = indices.clone();
+            flipped[last] ^= 1 << bit;
+            assert!(!Mnemonic::checksum_matches(&flipped, *mnemonic_type));
+            assert!(indices_to_entropy(&flipped, *mnemonic_type).is_err());
+        }
+
+        assert!(!Mnemonic::checksum_matches(&indices[1..], *mnemonic_type));
+    }
+
+    let mut out_of_range = entropy_to_indices(&[0u8; 16], MnemonicType::Type12Words).unwrap();
+    out_of_range[0] = 2048;
+    assert!(!Mnemonic::checksum_matches(&out_of_range, MnemonicType::Type12Words));
+}
+
+#[test]
+fn try_clone_valid_instance() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+
+    let copy = mnemonic.try_clone().unwrap();
+
+    assert_eq!(copy.as_str(), mnemonic.as_str());
+    assert_eq!(copy.as_entropy(), mnemonic.as_entropy());
+    assert_eq!(copy.as_seed().as_bytes(), mnemonic.as_seed().as_bytes());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn clones_keep_a_pending_seed() {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mnemonic = Mnemonic::new_lazy(MnemonicType::Type12Words, Language::English, "TREZOR", &mut rng).unwrap();
+    let expected = Mnemonic::from_string(mnemonic.as_str(), Language::English, "TREZOR").unwrap();
+
+    // cloned while the seed is pending, each copy derives it with the password
+    let pending = mnemonic.clone();
+    let checked = mnemonic.try_clone().unwrap();
+    assert_eq!(pending.as_seed().as_bytes(), expected.as_seed().as_bytes());
+    assert_eq!(checked.as_seed().as_bytes(), expected.as_seed().as_bytes());
+
+    // cloned once the seed is derived and the password dropped, each copy gets the seed
+    assert_eq!(mnemonic.as_seed().as_bytes(), expected.as_seed().as_bytes());
+    assert_eq!(mnemonic.clone().as_seed().as_bytes(), expected.as_seed().as_bytes());
+    assert_eq!(mnemonic.try_clone().unwrap().as_seed().as_bytes(), expected.as_seed().as_bytes());
+    assert_eq!(mnemonic.rebuild_canonical().as_seed().as_bytes(), expected.as_seed().as_bytes());
+}
+
+#[test]
+fn words_as_slice_after_normalize() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+
+    let mut mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English, "").unwrap();
+    let words: Vec<&str> = test_mnemonic.split(" ").collect();
+
+    assert_eq!(mnemonic.words_as_slice(), &words[..]);
+
+    mnemonic.normalize_phrase();
+    assert_eq!(mnemonic.words_as_slice(), &words[..]);
+    assert_eq!(mnemonic.words_as_slice().join(" "), mnemonic.as_str());
+}
+
+#[test]
+fn abbreviated_round_trip_24_words() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword world";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+
+    let abbreviated = mnemonic.to_abbreviated();
+    assert_eq!(abbreviated, "alwa gues retr devo warm poem gira thou priz read mapl daug girl feel clay sile lemo brac abst bask toe tiny swor worl");
+
+    let expanded = Mnemonic::from_abbreviated(&abbreviated, Language::English, "").unwrap();
+    assert_eq!(expanded.as_str(), test_mnemonic);
+    assert_eq!(expanded.as_entropy(), mnemonic.as_entropy());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn known_test_vectors_are_flagged() {
+    let word_list = Language::English.word_list();
+
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type18Words, MnemonicType::Type24Words] {
+        let entropy = vec![0u8; mnemonic_type.entropy_bits() / 8];
+        let mnemonic = Mnemonic::from_entropy_with_word_list(&entropy, *mnemonic_type, word_list, "").unwrap();
+        assert!(mnemonic.is_known_test_vector());
+    }
+
+    let hardhat = Mnemonic::from_string_with_word_list("test test test test test test test test test test test junk", word_list, "").unwrap();
+    assert!(hardhat.is_known_test_vector());
+
+    let mut rng = XorShiftRng::from_seed([13, 14, 15, 16]);
+    let random = Mnemonic::new_lazy(MnemonicType::Type12Words, Language::English, "", &mut rng).unwrap();
+    assert!(!random.is_known_test_vector());
+}
+
+#[test]
+fn words_count_matches_mnemonic_type() {
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let entropy = vec![0x5a; mnemonic_type.entropy_bits() / 8];
+
+        for language in &[Language::English, Language::Japanese] {
+            let mnemonic = Mnemonic::from_entropy(&entropy, *mnemonic_type, *language, "").unwrap();
+
+            assert_eq!(mnemonic.words().count(), mnemonic_type.word_count());
+            assert!(mnemonic.words().all(|word| language.word_list().words.iter().any(|w| w == word)));
+        }
+    }
+}
+
+#[test]
+fn word_count_and_language() {
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type18Words, MnemonicType::Type24Words] {
+        let entropy = vec![0x5a; mnemonic_type.entropy_bits() / 8];
+
+        for language in &[Language::English, Language::Japanese] {
+            let mnemonic = Mnemonic::from_entropy(&entropy, *mnemonic_type, *language, "").unwrap();
+
+            assert_eq!(mnemonic.word_count(), mnemonic_type.word_count());
+            assert_eq!(mnemonic.word_count(), mnemonic.words().count());
+            assert_eq!(mnemonic.language(), Some(*language));
+        }
+    }
+
+    let mut words = Language::English.word_list().words.clone();
+    words.reverse();
+    let custom = WordList::from_words("reversed".to_owned(), words).unwrap();
+
+    let mnemonic = Mnemonic::from_entropy_with_word_list(&[0x5a; 16], MnemonicType::Type12Words, &custom, "").unwrap();
+    assert_eq!(mnemonic.language(), None);
+}
+
+#[test]
+fn from_str_uses_english_and_no_password() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    let parsed: Mnemonic = test_mnemonic.parse().unwrap();
+    let expected = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+
+    assert_eq!(parsed.as_entropy(), expected.as_entropy());
+    assert_eq!(parsed.get_seed().as_bytes(), expected.get_seed().as_bytes());
+
+    assert!("remain park person kitchen mule spell knee armed position rail grid ankle".parse::<Mnemonic>().is_err());
+}
+
+#[test]
+fn display_matches_as_str() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+
+    assert_eq!(format!("{}", mnemonic), mnemonic.as_str());
+    assert_eq!(mnemonic.to_string(), test_mnemonic);
+
+    let japanese = Mnemonic::from_entropy(&[0x5a; 16], MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+    assert_eq!(format!("{}", japanese), japanese.as_str());
+}
+
+#[test]
+fn equality_uses_entropy_and_language() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+    let strict = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    let lenient = Mnemonic::from_string_lenient(format!("\"{}\"", test_mnemonic.replace(' ', ", ")), Language::English.word_list(), "TREZOR").unwrap();
+    let other = Mnemonic::from_string("crop cash unable insane eight faith inflict route frame loud box vibrant", Language::English, "").unwrap();
+    let japanese = Mnemonic::from_entropy(strict.as_entropy(), MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+
+    assert_eq!(strict, lenient);
+    assert_ne!(strict, other);
+    assert_ne!(strict, japanese);
+    assert!(strict.same_entropy(&japanese));
+
+    let mut unique: Vec<Mnemonic> = Vec::new();
+    for mnemonic in [strict, lenient, other, japanese] {
+        if !unique.contains(&mnemonic) {
+            unique.push(mnemonic);
+        }
+    }
+    assert_eq!(unique.len(), 3);
+}
+
+#[test]
+fn equality_compares_custom_word_list_words() {
+    let english = Language::English.word_list();
+
+    let mut reversed_words = english.words.clone();
+    reversed_words.reverse();
+    let reversed = WordList::from_words(english.language.clone(), reversed_words).unwrap();
+    let copy = WordList::from_words(english.language.clone(), english.words.clone()).unwrap();
+
+    let entropy = [0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    let standard = Mnemonic::from_entropy_with_word_list(&entropy, MnemonicType::Type12Words, english, "").unwrap();
+    let same_name = Mnemonic::from_entropy_with_word_list(&entropy, MnemonicType::Type12Words, &reversed, "").unwrap();
+    let same_words = Mnemonic::from_entropy_with_word_list(&entropy, MnemonicType::Type12Words, &copy, "").unwrap();
+
+    assert_ne!(standard.as_str(), same_name.as_str());
+    assert_ne!(standard, same_name);
+    assert_eq!(standard, same_words);
+}
+
+#[test]
+fn entropy_to_indices_matches_known_phrases() {
+    let entropy = [0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+
+    let indices = entropy_to_indices(&entropy, MnemonicType::Type12Words).unwrap();
+    assert_eq!(indices, vec![415, 282, 1890, 935, 567, 656, 923, 1508, 740, 1057, 212, 1947]);
+
+    let mnemonic = Mnemonic::from_entropy(&entropy, MnemonicType::Type12Words, Language::English, "").unwrap();
+    assert_eq!(mnemonic.as_str(), "crop cash unable insane eight faith inflict route frame loud box vibrant");
+
+    let mnemonic = Mnemonic::from_entropy(&[0x7f; 32], MnemonicType::Type24Words, Language::English, "").unwrap();
+    assert_eq!(mnemonic.as_str(), "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn to_indices_and_from_indices_round_trip() {
+    let mut rng = XorShiftRng::from_seed([21, 22, 23, 24]);
+
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        for language in &[Language::English, Language::Japanese] {
+            let mnemonic = Mnemonic::new_with_rng(*mnemonic_type, *language, "TREZOR", &mut rng).unwrap();
+
+            let indices = mnemonic.to_indices();
+            assert_eq!(indices.len(), mnemonic_type.word_count());
+
+            let expected: Vec<u16> = mnemonic.iter_with_indices().map(|(_, index, _)| index).collect();
+            assert_eq!(indices, expected);
+
+            let rebuilt = Mnemonic::from_indices(&indices, *language, "TREZOR").unwrap();
+            assert_eq!(rebuilt.as_str(), mnemonic.as_str());
+            assert_eq!(rebuilt.as_seed().as_bytes(), mnemonic.as_seed().as_bytes());
+        }
+    }
+
+    let mut indices = Mnemonic::from_string("park remain person kitchen mule spell knee armed position rail grid ankle", Language::English, "").unwrap().to_indices();
+    assert!(Mnemonic::from_indices(&indices[..11], Language::English, "").is_err());
+
+    indices[0] = 2048;
+    assert!(Mnemonic::from_indices(&indices, Language::English, "").is_err());
+
+    indices.swap(0, 1);
+    indices[1] = indices[2];
+    assert!(Mnemonic::from_indices(&indices, Language::English, "").is_err());
+}
+
+#[test]
+fn entropy_and_checksum_bits_follow_the_mnemonic_type() {
+    let expected = [(MnemonicType::Type12Words, 128, 4), (MnemonicType::Type15Words, 160, 5),
+                    (MnemonicType::Type18Words, 192, 6), (MnemonicType::Type21Words, 224, 7),
+                    (MnemonicType::Type24Words, 256, 8)];
+
+    for &(mnemonic_type, entropy_bits, checksum_bits) in &expected {
+        let entropy = vec![0x7fu8; entropy_bits / 8];
+        let mnemonic = Mnemonic::from_entropy(&entropy, mnemonic_type, Language::English, "").unwrap();
+
+        assert_eq!(mnemonic.entropy_bits(), entropy_bits);
+        assert_eq!(mnemonic.checksum_bits(), checksum_bits);
+        assert_eq!(mnemonic.entropy_bits(), mnemonic.as_entropy().len() * 8);
+        assert_eq!(mnemonic.entropy_bits() + mnemonic.checksum_bits(), mnemonic.word_count() * 11);
+    }
+}
+
+#[test]
+fn entropy_hex_in_both_cases() {
+    let test_mnemonic = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+
+    assert_eq!(mnemonic.get_entropy_hex(), "33E46BB13A746EA41CDDE45C90846A79");
+    assert_eq!(mnemonic.get_entropy_hex_lower(), "33e46bb13a746ea41cdde45c90846a79");
+
+    let round_trip = Mnemonic::from_entropy_hex(&mnemonic.get_entropy_hex_lower().to_uppercase(), MnemonicType::Type12Words, Language::English, "").unwrap();
+    assert_eq!(round_trip.as_str(), test_mnemonic);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn checksum_is_right_aligned() {
+    let mnemonic = Mnemonic::from_string("crop cash unable insane eight faith inflict route frame loud box vibrant", Language::English, "").unwrap();
+    assert_eq!(mnemonic.checksum(), 0x0b);
+
+    let phrase = format!("{} art", vec!["abandon"; 23].join(" "));
+    let mnemonic = Mnemonic::from_string(&*phrase, Language::English, "").unwrap();
+    assert_eq!(mnemonic.checksum(), 0x66);
+
+    let mut rng = XorShiftRng::from_seed([51, 52, 53, 54]);
+
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let mnemonic = Mnemonic::new_with_rng(*mnemonic_type, Language::English, "", &mut rng).unwrap();
+
+        let last = *mnemonic.to_indices().last().unwrap();
+        let mask = (1u16 << mnemonic.checksum_bits()) - 1;
+
+        assert_eq!(u16::from(mnemonic.checksum()), last & mask);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn word_list_variants_match_language_versions() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+
+    let by_language = Mnemonic::from_prefixes(&prefixes, Language::English, "").unwrap();
+    let by_word_list = Mnemonic::from_prefixes_with_word_list(&prefixes, word_list, "").unwrap();
+    assert_eq!(by_language.as_str(), test_mnemonic);
+    assert_eq!(by_word_list.as_str(), test_mnemonic);
+
+    let abbreviated = prefixes.join(" ");
+    assert_eq!(Mnemonic::from_abbreviated_with_word_list(&abbreviated, word_list, "").unwrap().as_str(), test_mnemonic);
+
+    let typo = "park remain persen kitchen mule spell knee armed position rail grid ankle";
+    let report = Mnemonic::validate_detailed(typo, Language::English);
+    let report_with_word_list = Mnemonic::validate_detailed_with_word_list(typo, word_list);
+    assert_eq!(report.invalid_words, report_with_word_list.invalid_words);
+    assert_eq!(report.invalid_words, vec![(2, "persen".to_owned())]);
+
+    let mut first = XorShiftRng::from_seed([61, 62, 63, 64]);
+    let mut second = XorShiftRng::from_seed([61, 62, 63, 64]);
+
+    let lazy = Mnemonic::new_lazy(MnemonicType::Type15Words, Language::English, "", &mut first).unwrap();
+    let lazy_with_word_list = Mnemonic::new_lazy_with_word_list(MnemonicType::Type15Words, word_list, "", &mut second).unwrap();
+    assert_eq!(lazy.as_str(), lazy_with_word_list.as_str());
+
+    let until = Mnemonic::generate_until(MnemonicType::Type12Words, Language::English, "", &mut first, |_| true, 1).unwrap();
+    let until_with_word_list = Mnemonic::generate_until_with_word_list(MnemonicType::Type12Words, word_list, "", &mut second, |_| true, 1).unwrap();
+    assert_eq!(until.as_str(), until_with_word_list.as_str());
+
+    let mixed = Mnemonic::new_mixed_entropy(b"31624455", MnemonicType::Type24Words, Language::English, "", &mut first).unwrap();
+    let mixed_with_word_list = Mnemonic::new_mixed_entropy_with_word_list(b"31624455", MnemonicType::Type24Words, word_list, "", &mut second).unwrap();
+    assert_eq!(mixed.as_str(), mixed_with_word_list.as_str());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn word_list_variants_use_the_custom_list() {
+    let mut words = Language::English.word_list().words.clone();
+    words.reverse();
+    let custom = WordList::from_words("reversed".to_owned(), words).unwrap();
+
+    let mut rng = XorShiftRng::from_seed([71, 72, 73, 74]);
+
+    let generated = [
+        Mnemonic::new_lazy_with_word_list(MnemonicType::Type12Words, &custom, "", &mut rng).unwrap(),
+        Mnemonic::generate_until_with_word_list(MnemonicType::Type12Words, &custom, "", &mut rng, |_| true, 1).unwrap(),
+        Mnemonic::new_mixed_entropy_with_word_list(b"3162", MnemonicType::Type12Words, &custom, "", &mut rng).unwrap(),
+    ];
+
+    for mnemonic in &generated {
+        assert_eq!(mnemonic.language(), None);
+        assert!(Mnemonic::validate_detailed_with_word_list(mnemonic.as_str(), &custom).is_ok());
+
+        let prefixes: Vec<&str> = mnemonic.words().collect();
+        let expanded = Mnemonic::from_prefixes_with_word_list(&prefixes, &custom, "").unwrap();
+        assert_eq!(expanded.as_entropy(), mnemonic.as_entropy());
+
+        let abbreviated = Mnemonic::from_abbreviated_with_word_list(mnemonic.as_str(), &custom, "").unwrap();
+        assert_eq!(abbreviated.as_entropy(), mnemonic.as_entropy());
+    }
+}
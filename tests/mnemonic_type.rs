@@ -0,0 +1,158 @@
+extern crate bip39;
+
+use ::bip39::{Error, ErrorKind, Language, Mnemonic, MnemonicType};
+
+#[test]
+fn for_phrase_rejects_very_long_input() {
+    let phrase = vec!["abandon"; 10_000].join(" ");
+
+    match MnemonicType::for_phrase(&*phrase) {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 10_000),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let word_list = Language::English.word_list();
+
+    match Mnemonic::validate_with_word_list(&*phrase, word_list) {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 10_000),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match Mnemonic::from_string(&*phrase, Language::English, "") {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 10_000),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match Mnemonic::from_string_with_word_list(&*phrase, word_list, "") {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 10_000),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn strength_label_for_each_type() {
+    let word_list = Language::English.word_list();
+
+    let expected = [(MnemonicType::Type12Words, "Standard (128-bit)"),
+                    (MnemonicType::Type15Words, "Enhanced (160-bit)"),
+                    (MnemonicType::Type18Words, "Strong (192-bit)"),
+                    (MnemonicType::Type21Words, "Very strong (224-bit)"),
+                    (MnemonicType::Type24Words, "Maximum (256-bit)")];
+
+    for &(mnemonic_type, label) in &expected {
+        assert_eq!(mnemonic_type.strength_label(), label);
+
+        let entropy = vec![0u8; mnemonic_type.entropy_bits() / 8];
+        let mnemonic = Mnemonic::from_entropy_with_word_list(&entropy, mnemonic_type, word_list, "").unwrap();
+        assert_eq!(mnemonic.strength_label(), label);
+    }
+}
+
+#[test]
+fn recommended_use_for_each_type() {
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let guidance = mnemonic_type.recommended_use();
+
+        assert!(!guidance.is_empty());
+        assert!(guidance.starts_with(&format!("{}-bit", mnemonic_type.entropy_bits())));
+    }
+}
+
+#[test]
+fn for_entropy_bits_rejects_invalid_sizes() {
+    for bits in &[128, 160, 192, 224, 256] {
+        assert_eq!(MnemonicType::for_entropy_bits(*bits).unwrap().entropy_bits(), *bits);
+    }
+
+    for bits in &[0, 96, 129, 200, 288] {
+        match MnemonicType::for_entropy_bits(*bits) {
+            Err(Error(ErrorKind::InvalidEntropyBits(b), _)) => assert_eq!(b, *bits),
+            other => panic!("unexpected result for {}: {:?}", bits, other)
+        }
+    }
+}
+
+#[test]
+fn for_word_count_and_key_size_accept_standard_values() {
+    let expected = [(12, 128), (15, 160), (18, 192), (21, 224), (24, 256)];
+
+    for &(word_count, key_size) in &expected {
+        let by_words = MnemonicType::for_word_count(word_count).unwrap();
+        assert_eq!(by_words.word_count(), word_count);
+        assert_eq!(by_words.entropy_bits(), key_size);
+
+        let by_key_size = MnemonicType::for_key_size(key_size).unwrap();
+        assert_eq!(by_key_size.word_count(), word_count);
+        assert_eq!(by_key_size.entropy_bits(), key_size);
+    }
+
+    match MnemonicType::for_word_count(13) {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 13),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match MnemonicType::for_key_size(129) {
+        Err(Error(ErrorKind::InvalidKeysize, _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn for_phrase_counts_words() {
+    for word_count in &[12, 15, 18, 21, 24] {
+        let phrase = vec!["abandon"; *word_count].join(" ");
+        assert_eq!(MnemonicType::for_phrase(&*phrase).unwrap().word_count(), *word_count);
+
+        // stray whitespace doesn't change the count
+        let spaced = format!("  {}\n", vec!["abandon"; *word_count].join(" \t "));
+        assert_eq!(MnemonicType::for_phrase(spaced).unwrap().word_count(), *word_count);
+
+        let ideographic = vec!["abandon"; *word_count].join("\u{3000}");
+        assert_eq!(MnemonicType::for_phrase(ideographic).unwrap().word_count(), *word_count);
+    }
+
+    match MnemonicType::for_phrase(vec!["abandon"; 13].join(" ")) {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 13),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match MnemonicType::for_phrase("") {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 0),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn display_and_from_str_round_trip() {
+    let expected = [(MnemonicType::Type12Words, "12 words (128bits)"),
+                    (MnemonicType::Type15Words, "15 words (160bits)"),
+                    (MnemonicType::Type18Words, "18 words (192bits)"),
+                    (MnemonicType::Type21Words, "21 words (224bits)"),
+                    (MnemonicType::Type24Words, "24 words (256bits)")];
+
+    for &(mnemonic_type, display) in &expected {
+        assert_eq!(mnemonic_type.to_string(), display);
+        assert_eq!(mnemonic_type.to_string().parse::<MnemonicType>().unwrap(), mnemonic_type);
+    }
+
+    for input in &["", "12", "12 words", "12 words (256bits)", " 12 words (128bits)", "13 words (128bits)"] {
+        match input.parse::<MnemonicType>() {
+            Err(Error(ErrorKind::InvalidMnemonicType(ref found), _)) => assert_eq!(found, input),
+            other => panic!("unexpected result for {:?}: {:?}", input, other)
+        }
+    }
+}
+
+#[test]
+fn total_bits_is_entropy_plus_checksum() {
+    let expected = [(MnemonicType::Type12Words, 132), (MnemonicType::Type15Words, 165), (MnemonicType::Type18Words, 198),
+                    (MnemonicType::Type21Words, 231), (MnemonicType::Type24Words, 264)];
+
+    for &(mnemonic_type, total_bits) in &expected {
+        assert_eq!(mnemonic_type.total_bits(), total_bits);
+        assert_eq!(mnemonic_type.total_bits(), mnemonic_type.word_count() * 11);
+        assert_eq!(mnemonic_type.total_bits(), mnemonic_type.entropy_bits() + mnemonic_type.checksum_bits());
+        assert_eq!(mnemonic_type.checksum_bits(), mnemonic_type.entropy_bits() / 32);
+    }
+}
@@ -0,0 +1,48 @@
+//! The parts of the crate that also build without the `std` feature
+//!
+//! Without `std` the crate hashes with its own SHA-2 and PBKDF2 instead of ring, so run these
+//! with `--no-default-features` as well as with the defaults.
+
+extern crate bip39;
+extern crate serde_json;
+
+use std::env;
+use std::fs;
+use ::bip39::{Error, ErrorKind, Language, Mnemonic, MnemonicType, TestVector};
+
+#[test]
+fn official_vectors_pass() {
+    let mut path = env::current_dir().unwrap();
+    path.push("tests/vectors/english.json");
+
+    let vectors: Vec<TestVector> = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert!(!vectors.is_empty());
+
+    for vector in vectors {
+        vector.check(Language::English.word_list()).unwrap();
+    }
+}
+
+#[test]
+fn from_entropy_and_from_string_round_trip() {
+    let entropy = [0x7f; 32];
+
+    let mnemonic = Mnemonic::from_entropy(&entropy, MnemonicType::Type24Words, Language::English, "").unwrap();
+    assert_eq!(mnemonic.as_str(), "legal winner thank year wave sausage worth useful legal winner thank year \
+                                   wave sausage worth useful legal winner thank year wave sausage worth title");
+
+    let parsed = Mnemonic::from_string(mnemonic.as_str(), Language::English, "").unwrap();
+    assert_eq!(parsed.as_entropy(), &entropy[..]);
+}
+
+#[test]
+fn validate_rejects_a_bad_checksum() {
+    assert!(Mnemonic::validate("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                               Language::English).is_ok());
+
+    match Mnemonic::validate("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+                             Language::English) {
+        Err(Error(ErrorKind::InvalidChecksum(..), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+//! Runs a file of entropy → phrase → seed vectors against a word list.
+//!
+//! By default this checks the bundled English vectors, but any word list and vector file can be
+//! supplied with the `BIP39_WORDLIST` and `BIP39_VECTORS` environment variables, for example:
+//!
+//! ```text
+//! BIP39_WORDLIST=/path/to/list.json BIP39_VECTORS=/path/to/vectors.json cargo test --test run_vectors
+//! ```
+extern crate bip39;
+
+use std::env;
+use std::path::PathBuf;
+use ::bip39::{Mnemonic, TestVector};
+
+fn path_from_env(var: &str, default: &str) -> PathBuf {
+    match env::var_os(var) {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let mut path = env::current_dir().unwrap();
+            path.push(default);
+            path
+        }
+    }
+}
+
+#[test]
+fn run_vectors() {
+    let word_list_path = path_from_env("BIP39_WORDLIST", "src/english.json");
+    let vectors_path = path_from_env("BIP39_VECTORS", "tests/vectors/english.json");
+
+    let word_list = Mnemonic::get_word_list(word_list_path).unwrap();
+    let vectors = TestVector::load(vectors_path).unwrap();
+
+    assert!(!vectors.is_empty());
+
+    for vector in vectors {
+        if let Err(e) = vector.check(&word_list) {
+            panic!("{} ({})", e, vector.entropy);
+        }
+    }
+}
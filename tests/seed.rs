@@ -0,0 +1,337 @@
+extern crate bip39;
+
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::fs::{self, File};
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::process;
+use ::bip39::{Error, ErrorKind, Language, Mnemonic, MnemonicType, Seed, WalletFormat, WordList};
+#[cfg(feature = "std")]
+use ::bip39::TestVector;
+
+#[test]
+fn seed_display_hex() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+    let seed = mnemonic.as_seed();
+
+    let displayed = format!("{}", seed.display_hex());
+
+    assert_eq!(displayed, seed.get_hex_lower());
+    assert_eq!(displayed.len(), 128);
+    assert_eq!(displayed.to_uppercase(), seed.as_hex());
+}
+
+#[test]
+fn debug_output_is_redacted() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "correct horse").unwrap();
+    let seed = mnemonic.as_seed();
+
+    let formatted = [format!("{:?}", mnemonic), format!("{:#?}", mnemonic), format!("{:?}", seed), format!("{:?}", Some(seed))];
+
+    assert_eq!(formatted[0], "Mnemonic { language: Some(English), .. }");
+    assert_eq!(formatted[2], "Seed { .. }");
+
+    for output in &formatted {
+        assert!(!output.contains("correct horse"));
+        assert!(!output.contains("park"));
+        assert!(!output.to_lowercase().contains(&seed.get_hex_lower()[..16]));
+        assert!(!output.to_lowercase().contains(&mnemonic.get_entropy_hex_lower()[..16]));
+
+        // no run of hex digits long enough to be part of a secret
+        let longest_hex_run = output.split(|c: char| !c.is_ascii_hexdigit()).map(str::len).max().unwrap();
+        assert!(longest_hex_run < 8, "hex in {:?}", output);
+    }
+}
+
+#[test]
+fn seed_to_wallet_format() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "TREZOR").unwrap();
+    let seed = mnemonic.as_seed();
+
+    assert_eq!(seed.to_wallet_format(WalletFormat::Hex), seed.get_hex_lower());
+    assert_eq!(seed.to_wallet_format(WalletFormat::Base58Check(0x80)),
+               "2HMoZ7mQxvD6Bc8SEbB9txQFYqrXy25aiYEn8z9W1gt1mi6TRvThPNNB4ZRLTLr8UoR178tANAfU1etMS3w81z78ePvhtgv");
+    assert_eq!(seed.to_wallet_format(WalletFormat::Base58Check(0x00)),
+               "1SpHdtNTeg7DEMAs9FnvgmooaTHaLqtvW8Ky89vKnXR8bAfSKhjZvB8Hkq5VrXjTVwDsgKWmX67TSeShy36bhtWtfsVpXC");
+}
+
+#[test]
+fn seed_empty_passphrase_matches_reference() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+
+    assert_eq!(mnemonic.as_seed().get_hex_lower(),
+               "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn seed_bip39_matches_vectors() {
+    let mut path = env::current_dir().unwrap();
+    path.push("tests/vectors/english.json");
+
+    for vector in TestVector::load(path).unwrap() {
+        assert_eq!(vector.passphrase, "TREZOR");
+
+        let seed = Seed::bip39(&vector.mnemonic, &vector.passphrase);
+        assert_eq!(seed.get_hex_lower(), vector.seed.to_lowercase());
+    }
+}
+
+#[test]
+fn seed_bip39_skips_validation_and_normalizes() {
+    // twelve "abandon"s has a bad checksum, but still gets a seed
+    let phrase = ["abandon"; 12].join(" ");
+    assert_eq!(Seed::bip39(&phrase, "").get_hex_lower(),
+               "94cfb81f135f8d85d787a84173cf1e9fc51792f3723e2b93a162fa57a03370fd80971d026eed300544116dfee4d5b375c77ea86b65dfd44e2ecda58044684fe0");
+
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let composed = Seed::bip39(test_mnemonic, "caf\u{e9}");
+    let decomposed = Seed::bip39(test_mnemonic, "cafe\u{301}");
+
+    assert_eq!(composed.as_bytes(), decomposed.as_bytes());
+    assert_eq!(composed.get_hex_lower(),
+               "af8bbd2566df7b69d926f2b09dfdbd75db6c994a3399b2cc65f928d63e3fd4e61218ee0d15f8c810be4d45e66d47b43c15a5cc753976b1666912377ff7ae9818");
+}
+
+#[test]
+fn seed_differs_across_word_lists_with_same_entropy() {
+    let english = Language::English.word_list();
+
+    // stand-in for a translation: the same words in a different order give a different phrase
+    // for the same entropy, exactly like a word list for another language would
+    let mut words = english.words.clone();
+    words.reverse();
+    let translated = WordList::from_words("reversed".to_owned(), words).unwrap();
+
+    let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+
+    let english_mnemonic = Mnemonic::from_entropy_with_word_list(entropy, MnemonicType::Type12Words, english, "").unwrap();
+    let translated_mnemonic = Mnemonic::from_entropy_with_word_list(entropy, MnemonicType::Type12Words, &translated, "").unwrap();
+
+    assert!(english_mnemonic.same_entropy(&translated_mnemonic));
+    assert_ne!(english_mnemonic.as_str(), translated_mnemonic.as_str());
+    assert_ne!(english_mnemonic.get_seed().as_bytes(), translated_mnemonic.get_seed().as_bytes());
+}
+
+#[test]
+fn seed_is_64_bytes_and_derived_once() {
+    let word_list = Language::English.word_list();
+
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type15Words, MnemonicType::Type18Words,
+                           MnemonicType::Type21Words, MnemonicType::Type24Words] {
+        let entropy = vec![0x5a; mnemonic_type.entropy_bits() / 8];
+        let mnemonic = Mnemonic::from_entropy_with_word_list(&entropy, *mnemonic_type, word_list, "TREZOR").unwrap();
+
+        assert_eq!(mnemonic.as_seed().as_bytes().len(), 64);
+        assert_eq!(mnemonic.as_seed().as_hex().len(), 128);
+
+        // the seed is stored, not derived again on each access
+        assert!(std::ptr::eq(mnemonic.as_seed(), mnemonic.as_seed()));
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn seed_matches_vectors_when_first_requested_later() {
+    let mut path = env::current_dir().unwrap();
+    path.push("tests/vectors/english.json");
+
+    let vectors = TestVector::load(path).unwrap();
+
+    // every phrase is validated and stored before any of the seeds are asked for
+    let mnemonics: Vec<Mnemonic> = vectors.iter()
+        .map(|vector| Mnemonic::from_string(&*vector.mnemonic, Language::English, &*vector.passphrase).unwrap())
+        .collect();
+
+    for (vector, mnemonic) in vectors.iter().zip(&mnemonics) {
+        assert_eq!(mnemonic.as_seed().get_hex_lower(), vector.seed.to_lowercase());
+        assert!(std::ptr::eq(mnemonic.as_seed(), mnemonic.as_seed()));
+    }
+}
+
+#[test]
+fn seed_passphrase_is_normalized() {
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let composed = Mnemonic::from_string(test_mnemonic, Language::English, "caf\u{e9}").unwrap();
+    let decomposed = Mnemonic::from_string(test_mnemonic, Language::English, "cafe\u{301}").unwrap();
+
+    assert_eq!(composed.get_seed().as_bytes(), decomposed.get_seed().as_bytes());
+    assert_eq!(composed.get_seed().get_hex_lower(),
+               "af8bbd2566df7b69d926f2b09dfdbd75db6c994a3399b2cc65f928d63e3fd4e61218ee0d15f8c810be4d45e66d47b43c15a5cc753976b1666912377ff7ae9818");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn seed_french_phrase_is_normalized() {
+    let mut path = env::current_dir().unwrap();
+    path.push("src/french.json");
+
+    let word_list = Mnemonic::get_word_list(path).unwrap();
+
+    // computed with the Python reference implementation, which NFKD normalizes the phrase and
+    // passphrase the same way
+    let vectors = [("80808080808080808080808080808080",
+                    "0C1ECE83A464688D74744723D609E30E191D05AB8C082CF34BB2405BC4363DBCF6A9F83707B577D230728B3943920F876EC844E86DD0D117152C23802D25BE3F"),
+                   ("9F6A2878B2520799A44EF18BC7DF394E7061A224D2C33CD015B157D746869863",
+                    "08CD47B905DF56E3BFBCA6D1DDB7EE7AE75D45F6E5928D337BACF34754D392C7225C611136148E130DC516CDC7ADE8E8A95BA62CCFDAC01A107875CE3E2CEFD2")];
+
+    for &(entropy, seed) in &vectors {
+        let mnemonic_type = MnemonicType::for_key_size(entropy.len() * 4).unwrap();
+        let entropy: Vec<u8> = (0..entropy.len()).step_by(2).map(|i| u8::from_str_radix(&entropy[i..i + 2], 16).unwrap()).collect();
+
+        let mnemonic = Mnemonic::from_entropy_with_word_list(&entropy, mnemonic_type, &word_list, "TREZOR").unwrap();
+        assert_eq!(mnemonic.get_seed().as_hex(), seed);
+
+        // a composed copy of the phrase is normalized to the (decomposed) word list and gives the
+        // same seed
+        let composed = mnemonic.as_str().replace("e\u{301}", "\u{e9}").replace("e\u{300}", "\u{e8}");
+        assert_ne!(composed, mnemonic.as_str());
+        assert_eq!(Seed::bip39(&composed, "TREZOR").as_hex(), seed);
+        assert!(Mnemonic::validate_with_word_list(&*composed, &word_list).is_ok());
+
+        let parsed = Mnemonic::from_string_with_word_list(&*composed, &word_list, "TREZOR").unwrap();
+        assert_eq!(parsed.as_str(), mnemonic.as_str());
+        assert_eq!(parsed.get_seed().as_hex(), seed);
+    }
+}
+
+#[test]
+fn seed_generate_with_iterations() {
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let standard = Seed::generate_with_iterations(phrase, "TREZOR", 2048).unwrap();
+    assert_eq!(standard.get_hex_lower(),
+               "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+    assert_eq!(standard.as_bytes(), Seed::bip39(phrase, "TREZOR").as_bytes());
+
+    let single = Seed::generate_with_iterations(phrase, "TREZOR", 1).unwrap();
+    assert_eq!(single.get_hex_lower(),
+               "93b97bc5354ec701eae542a773d5de60660589bf0d9a171061230590acbc1754004a1cd231299f2e3a44ec43472c2b276ae9868caacbc02bcfb662fb3a6f9a46");
+
+    let other = Seed::generate_with_iterations(phrase, "TREZOR", 4096).unwrap();
+    assert_ne!(other.as_bytes(), standard.as_bytes());
+    assert_ne!(other.as_bytes(), single.as_bytes());
+}
+
+#[test]
+fn seed_generate_with_zero_iterations_is_an_error() {
+    match Seed::generate_with_iterations("abandon about", "", 0) {
+        Err(Error(ErrorKind::InvalidIterations, _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn seed_from_hex_round_trips() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+    let seed = mnemonic.as_seed();
+
+    let from_upper = Seed::from_hex(seed.as_hex()).unwrap();
+    assert_eq!(from_upper.as_bytes(), seed.as_bytes());
+    assert_eq!(from_upper.as_hex(), seed.as_hex());
+
+    let from_lower = Seed::from_hex(&seed.get_hex_lower()).unwrap();
+    assert_eq!(from_lower.as_bytes(), seed.as_bytes());
+    assert_eq!(from_lower.as_hex(), seed.as_hex());
+
+    for length in &[0, 64, 127, 130] {
+        match Seed::from_hex(&"A".repeat(*length)) {
+            Err(Error(ErrorKind::InvalidSeedLength(found), _)) => assert_eq!(found, *length),
+            other => panic!("unexpected result for {}: {:?}", length, other.map(|s| s.get_hex_lower()))
+        }
+    }
+
+    let not_hex = format!("{}ZZ", &seed.as_hex()[..126]);
+    match Seed::from_hex(&not_hex) {
+        Err(Error(ErrorKind::DataDecode(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|s| s.get_hex_lower()))
+    }
+}
+
+#[test]
+fn seed_to_array_matches_as_bytes() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+    let seed = mnemonic.as_seed();
+
+    let array = seed.to_array();
+
+    assert_eq!(seed.as_bytes().len(), 64);
+    assert_eq!(array.len(), 64);
+    assert_eq!(&array[..], seed.as_bytes());
+    assert_eq!(&array[..], AsRef::<[u8]>::as_ref(seed));
+}
+
+#[test]
+fn seed_ct_eq_compares_every_byte() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+    let seed = mnemonic.as_seed();
+
+    assert!(seed.ct_eq(seed));
+    assert!(seed.ct_eq(&seed.to_owned()));
+    assert!(seed.ct_eq(&Seed::from_hex(seed.as_hex()).unwrap()));
+
+    let other = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    assert!(!seed.ct_eq(other.as_seed()));
+
+    // seeds that only differ in their last byte
+    let mut hex = seed.get_hex_lower();
+    let last = if hex.ends_with('0') { "1" } else { "0" };
+    hex.replace_range(127.., last);
+    assert!(!seed.ct_eq(&Seed::from_hex(&hex).unwrap()));
+}
+
+#[test]
+fn seed_to_master_key_matches_bip32_vector() {
+    // BIP32 test vector 3, the first one with a 64 byte seed like BIP39 produces
+    let seed = Seed::from_hex("4b381541583be4423346c643850da4b320e46a87ae3d2a4e6da11eba819cd4acba45d239319ac14f863b8d5ab5a0d0c64d2e8a1e7d1457df2e5a3c51c73235be").unwrap();
+
+    let master_key = seed.to_master_key().unwrap();
+
+    assert_eq!(master_key.private_key, [0x00, 0xdd, 0xb8, 0x0b, 0x06, 0x7e, 0x0d, 0x49, 0x93, 0x19, 0x7f, 0xe1, 0x0f, 0x26, 0x57, 0xa8,
+                                        0x44, 0xa3, 0x84, 0x58, 0x98, 0x47, 0x60, 0x2d, 0x56, 0xf0, 0xc6, 0x29, 0xc8, 0x1a, 0xae, 0x32]);
+    assert_eq!(master_key.chain_code, [0x01, 0xd2, 0x8a, 0x3e, 0x53, 0xcf, 0xfa, 0x41, 0x9e, 0xc1, 0x22, 0xc9, 0x68, 0xb3, 0x25, 0x9e,
+                                       0x16, 0xb6, 0x50, 0x76, 0x49, 0x54, 0x94, 0xd9, 0x7c, 0xae, 0x10, 0xbb, 0xfe, 0xc3, 0xc3, 0x6f]);
+
+    assert_eq!(format!("{:?}", master_key), "MasterKey { .. }");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn load_vectors_reports_errors() {
+    let mut truncated_path = env::temp_dir();
+    truncated_path.push(format!("bip39-truncated-vectors-{}.json", process::id()));
+    File::create(&truncated_path).unwrap().write_all(b"[{\"entropy\": \"00").unwrap();
+
+    let result = TestVector::load(truncated_path.clone());
+    fs::remove_file(&truncated_path).unwrap();
+
+    match result {
+        Err(Error(ErrorKind::WordListParse(ref e), _)) => assert!(e.is_eof()),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    let mut missing_path = env::temp_dir();
+    missing_path.push("bip39-missing-vectors.json");
+
+    match TestVector::load(missing_path) {
+        Err(Error(ErrorKind::InvalidFile, _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "serialize")]
+
+extern crate bip39;
+extern crate serde_json;
+
+use ::bip39::{Language, Mnemonic, MnemonicType};
+
+#[test]
+fn mnemonic_round_trips_through_json() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+
+    let json = serde_json::to_string(&mnemonic).unwrap();
+    assert_eq!(json, format!("{{\"phrase\":\"{}\",\"language\":\"english\"}}", test_mnemonic));
+    assert!(!json.contains("TREZOR"));
+
+    let parsed: Mnemonic = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, mnemonic);
+    assert_eq!(parsed.as_str(), test_mnemonic);
+
+    // the password isn't stored, so the seed is the one for an empty password
+    let unprotected = Mnemonic::from_string(test_mnemonic, Language::English, "").unwrap();
+    assert_eq!(parsed.as_seed().as_bytes(), unprotected.as_seed().as_bytes());
+
+    let japanese = Mnemonic::from_entropy(&[0x7f; 32], MnemonicType::Type24Words, Language::Japanese, "").unwrap();
+    let parsed: Mnemonic = serde_json::from_str(&serde_json::to_string(&japanese).unwrap()).unwrap();
+    assert_eq!(parsed, japanese);
+    assert_eq!(parsed.language(), Some(Language::Japanese));
+}
+
+#[test]
+fn deserialize_validates_the_phrase() {
+    let invalid = [
+        r#"{"phrase":"remain park person kitchen mule spell knee armed position rail grid ankle","language":"english"}"#,
+        r#"{"phrase":"park remain person kitchen mule spell knee armed position rail grid ankle","language":"klingon"}"#,
+        r#"{"phrase":"park remain person kitchen mule spell knee armed position rail grid ankle","language":"japanese"}"#,
+        r#"{"phrase":"park remain person kitchen mule spell knee armed position rail grid ankle"}"#,
+    ];
+
+    for json in &invalid {
+        assert!(serde_json::from_str::<Mnemonic>(json).is_err(), "{}", json);
+    }
+}
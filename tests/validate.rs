@@ -1,32 +1,24 @@
 extern crate bip39;
 extern crate serde;
-extern crate serde_json;
+#[cfg(feature = "std")]
+extern crate rand;
 
+#[cfg(feature = "std")]
 use std::env;
-use std::path::PathBuf;
-use std::fs::File;
-use ::bip39::Mnemonic;
+#[cfg(feature = "std")]
+use rand::{Rng, SeedableRng, XorShiftRng};
+use ::bip39::{entropy_to_indices, indices_to_entropy, ChecksumStatus, Error, ErrorKind, Language, Mnemonic, MnemonicType, WordList};
+#[cfg(feature = "std")]
+use ::bip39::validate_phrase;
 
 
-#[derive(Debug, Clone)]
-pub struct WordList {
-    pub language: String,
-    pub words: Vec<String>
-}
-
 #[test]
 fn validate_12_english() {
     let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
 
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
-    let file = File::open(path).unwrap();
-    let word_list = serde_json::from_reader(file).expect("Could not read file");
-
-    let _ = match Mnemonic::from_string(test_mnemonic, word_list, "") {
+    let _ = match Mnemonic::from_string(test_mnemonic, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 }
 
@@ -34,15 +26,9 @@ fn validate_12_english() {
 fn validate_15_english() {
     let test_mnemonic = "any paddle cabbage armor atom satoshi fiction night wisdom nasty they midnight chicken play phone";
 
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
-    let file = File::open(path).unwrap();
-    let word_list = serde_json::from_reader(file).expect("Could not read file");
-
-    let _ = match Mnemonic::from_string(test_mnemonic, word_list, "") {
+    let _ = match Mnemonic::from_string(test_mnemonic, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 }
 
@@ -50,15 +36,9 @@ fn validate_15_english() {
 fn validate_18_english() {
     let test_mnemonic = "soda oak spy claim best oppose gun ghost school use sign shock sign pipe vote follow category filter";
 
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
-    let file = File::open(path).unwrap();
-    let word_list = serde_json::from_reader(file).expect("Could not read file");
-
-    let _ = match Mnemonic::from_string(test_mnemonic, word_list, "") {
+    let _ = match Mnemonic::from_string(test_mnemonic, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 }
 
@@ -67,15 +47,9 @@ fn validate_18_english() {
 fn validate_21_english() {
     let test_mnemonic = "quality useless orient offer pole host amazing title only clog sight wild anxiety gloom market rescue fan language entry fan oyster";
 
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
-    let file = File::open(path).unwrap();
-    let word_list = serde_json::from_reader(file).expect("Could not read file");
-
-    let _ = match Mnemonic::from_string(test_mnemonic, word_list, "") {
+    let _ = match Mnemonic::from_string(test_mnemonic, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 }
 
@@ -84,15 +58,9 @@ fn validate_21_english() {
 fn validate_24_english() {
     let test_mnemonic = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword world";
 
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
-
-    let file = File::open(path).unwrap();
-    let word_list = serde_json::from_reader(file).expect("Could not read file");
-
-    let _ = match Mnemonic::from_string(test_mnemonic, word_list, "") {
+    let _ = match Mnemonic::from_string(test_mnemonic, Language::English, "") {
         Ok(b) => b,
-        Err(_) => { assert!(false); return }
+        Err(e) => panic!("{}", e)
     };
 }
 
@@ -101,14 +69,452 @@ fn validate_24_english() {
 fn validate_12_english_uppercase() {
     let invalid_mnemonic = "Park remain person kitchen mule spell knee armed position rail grid ankle";
 
-    let mut path = PathBuf::from(env::current_dir().unwrap());
-    path.push("src/english.json");
+    assert!(Mnemonic::from_string(invalid_mnemonic, Language::English, "").is_err());
+}
 
-    let file = File::open(path).unwrap();
-    let word_list = serde_json::from_reader(file).expect("Could not read file");
+#[test]
+fn validate_with_borrowed_word_list() {
+    let word_list = Language::English.word_list();
 
-    let _ = match Mnemonic::from_string(invalid_mnemonic, word_list, "") {
-        Ok(_) => { assert!(false); return },
-        Err(_) => {},
-    };
+    let phrases = [
+        "park remain person kitchen mule spell knee armed position rail grid ankle",
+        "any paddle cabbage armor atom satoshi fiction night wisdom nasty they midnight chicken play phone",
+    ];
+
+    for phrase in &phrases {
+        assert!(Mnemonic::validate_with_word_list(*phrase, word_list).is_ok());
+
+        let borrowed = Mnemonic::from_string_with_word_list(*phrase, word_list, "").unwrap();
+        let owned = Mnemonic::from_string(*phrase, Language::English, "").unwrap();
+        assert_eq!(borrowed.as_str(), owned.as_str());
+        assert_eq!(borrowed.as_entropy(), owned.as_entropy());
+        assert_eq!(borrowed.as_seed().as_hex(), owned.as_seed().as_hex());
+    }
+
+    assert!(Mnemonic::validate_with_word_list("Park remain person kitchen mule spell knee armed position rail grid ankle", word_list).is_err());
+}
+
+#[test]
+fn validate_12_english_prefixes() {
+    let word_list = Language::English.word_list();
+
+    let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+    let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English, "").unwrap();
+    let expected = Mnemonic::from_string_with_word_list("park remain person kitchen mule spell knee armed position rail grid ankle", word_list, "").unwrap();
+
+    assert_eq!(mnemonic.as_str(), expected.as_str());
+    assert_eq!(mnemonic.as_seed().as_hex(), expected.as_seed().as_hex());
+
+    let mut ambiguous = prefixes;
+    ambiguous[1] = "re";
+    match Mnemonic::from_prefixes(&ambiguous, Language::English, "") {
+        Err(Error(ErrorKind::AmbiguousPrefix(ref p), _)) if p == "re" => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+
+    let mut unknown = prefixes;
+    unknown[1] = "zzzz";
+    match Mnemonic::from_prefixes(&unknown, Language::English, "") {
+        Err(Error(ErrorKind::UnknownPrefix(ref p), _)) if p == "zzzz" => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+    // "ank" only matches "ankle", but is still shorter than 4 characters
+    let mut short = prefixes;
+    short[11] = "ank";
+    match Mnemonic::from_prefixes(&short, Language::English, "") {
+        Err(Error(ErrorKind::PrefixTooShort(ref p), _)) if p == "ank" => {},
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn validate_any_finds_matching_word_list() {
+    let english = Language::English.word_list();
+
+    // a second list with the same words in a different order, so phrases only validate in one
+    let mut words = english.words.clone();
+    words.reverse();
+    let reversed = WordList::from_words("reversed".to_owned(), words).unwrap();
+
+    let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    let mnemonic = Mnemonic::from_entropy_with_word_list(entropy, MnemonicType::Type12Words, &reversed, "").unwrap();
+
+    let matched = Mnemonic::validate_any_with_word_lists(mnemonic.as_str(), &[english, &reversed]).unwrap();
+    assert_eq!(matched.language, "reversed");
+    assert_eq!(matched.as_language(), None);
+
+    match Mnemonic::validate_any_with_word_lists(mnemonic.as_str(), &[english]) {
+        Err(Error(ErrorKind::NoMatchingLanguage(failures), _)) => assert_eq!(failures.len(), 1),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn validate_any_finds_matching_language() {
+    let entropy = &[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79];
+    let japanese = Mnemonic::from_entropy(entropy, MnemonicType::Type12Words, Language::Japanese, "").unwrap();
+
+    let matched = Mnemonic::validate_any(japanese.as_str(), &[Language::English, Language::Japanese]).unwrap();
+    assert_eq!(matched, Language::Japanese);
+
+    match Mnemonic::validate_any(japanese.as_str(), &[Language::English]) {
+        Err(Error(ErrorKind::NoMatchingLanguage(failures), _)) => {
+            assert_eq!(failures.len(), 1);
+            assert!(failures[0].starts_with("english: "));
+        },
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match Mnemonic::validate_any(japanese.as_str(), &[]) {
+        Err(Error(ErrorKind::NoMatchingLanguage(failures), _)) => assert!(failures.is_empty()),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[cfg(feature = "lang-french")]
+#[test]
+fn validate_any_finds_french() {
+    let test_mnemonic = "cloporte brigade tolérant graine dioxyde empereur gomme poésie ethnie intuitif bataille union";
+
+    let matched = Mnemonic::validate_any(test_mnemonic, &[Language::English, Language::French]).unwrap();
+    assert_eq!(matched, Language::French);
+}
+
+#[test]
+fn validate_12_english_lenient() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let expected = Mnemonic::from_string_with_word_list(test_mnemonic, word_list, "").unwrap();
+
+    let inputs = [
+        "\"park remain person kitchen mule spell knee armed position rail grid ankle\"",
+        "park,remain,person,kitchen,mule,spell,knee,armed,position,rail,grid,ankle",
+        "[park, remain, person, kitchen, mule, spell, knee, armed, position, rail, grid, ankle]",
+        "[\"park\", \"remain\", \"person\", \"kitchen\", \"mule\", \"spell\", \"knee\", \"armed\", \"position\", \"rail\", \"grid\", \"ankle\"]",
+        "  'park  remain\tperson kitchen mule spell knee armed position rail grid ankle'\n",
+    ];
+
+    for input in &inputs {
+        let mnemonic = Mnemonic::from_string_lenient(*input, word_list, "").unwrap();
+        assert_eq!(mnemonic.as_str(), test_mnemonic);
+        assert_eq!(mnemonic.as_seed().as_hex(), expected.as_seed().as_hex());
+
+        assert!(Mnemonic::validate_with_word_list(*input, word_list).is_err());
+    }
+}
+
+#[test]
+fn validate_detailed_reports_every_problem() {
+    let report = Mnemonic::validate_detailed("park remain person kitchen mule spell knee armed position rail grid ankle", Language::English);
+    assert!(report.is_ok());
+    assert_eq!(report.word_count, 12);
+    assert_eq!(report.checksum, ChecksumStatus::Valid);
+
+    // swapping the first two words keeps every word valid but breaks the checksum
+    let report = Mnemonic::validate_detailed("remain park person kitchen mule spell knee armed position rail grid ankle", Language::English);
+    assert!(!report.is_ok());
+    assert!(report.invalid_words.is_empty());
+    assert_eq!(report.checksum, ChecksumStatus::Invalid);
+
+    // a wrong word count and two invalid words are all reported together
+    let report = Mnemonic::validate_detailed("Park remain person kitchen mule spell knee armed positon rail grid", Language::English);
+    assert!(!report.is_ok());
+    assert_eq!(report.word_count, 11);
+    assert!(report.mnemonic_type.is_none());
+    assert_eq!(report.invalid_words, vec![(0, "Park".to_owned()), (8, "positon".to_owned())]);
+    assert_eq!(report.checksum, ChecksumStatus::Unchecked);
+}
+
+#[test]
+fn validate_detailed_leaves_the_checksum_unchecked_with_an_invalid_word() {
+    // the swapped first two words break the checksum, and the last word is misspelled
+    let report = Mnemonic::validate_detailed("remain park person kitchen mule spell knee armed position rail grid ankel", Language::English);
+    assert!(!report.is_ok());
+    assert_eq!(report.invalid_words, vec![(11, "ankel".to_owned())]);
+    assert_eq!(report.checksum, ChecksumStatus::Unchecked);
+
+    // the checksum can't be called invalid, other last words make the same phrase pass
+    let partial = ["remain", "park", "person", "kitchen", "mule", "spell", "knee", "armed", "position", "rail", "grid"];
+    let last_words = Mnemonic::valid_last_words(&partial, Language::English).unwrap();
+    assert_eq!(last_words.len(), 128);
+
+    let corrected = format!("{} {}", partial.join(" "), last_words[0]);
+    assert!(Mnemonic::validate_detailed(&*corrected, Language::English).is_ok());
+}
+
+#[test]
+fn validate_two_phrases_against_one_borrowed_word_list() {
+    let word_list = Language::English.word_list();
+
+    let first = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let second = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+    Mnemonic::validate_with_word_list(first, word_list).unwrap();
+    Mnemonic::validate_with_word_list(second, word_list).unwrap();
+
+    let first = Mnemonic::from_string_with_word_list(first, word_list, "").unwrap();
+    let second = Mnemonic::from_string_with_word_list(second, word_list, "").unwrap();
+
+    assert_ne!(first.as_entropy(), second.as_entropy());
+    assert_eq!(word_list.words.len(), 2048);
+}
+
+#[test]
+fn validate_rejects_each_corrupted_checksum_bit() {
+    let entropy = [0x7fu8; 32];
+    let indices = entropy_to_indices(&entropy, MnemonicType::Type24Words).unwrap();
+
+    // the last word of a 24 word phrase carries the 8 checksum bits in its low bits
+    for bit in 0..8 {
+        let mut corrupted = indices.clone();
+        corrupted[23] ^= 1 << bit;
+
+        match indices_to_entropy(&corrupted, MnemonicType::Type24Words) {
+            Err(Error(ErrorKind::InvalidChecksum(expected, found), _)) => {
+                assert_eq!(expected, (indices[23] & 0xff) as u8);
+                assert_eq!(found, expected ^ (1 << bit));
+            },
+            other => panic!("unexpected result for bit {}: {:?}", bit, other)
+        }
+        assert!(!Mnemonic::checksum_matches(&corrupted, MnemonicType::Type24Words));
+    }
+
+    assert_eq!(&indices_to_entropy(&indices, MnemonicType::Type24Words).unwrap()[..], &entropy[..]);
+
+    match Mnemonic::validate("remain park person kitchen mule spell knee armed position rail grid ankle", Language::English) {
+        Err(Error(ErrorKind::InvalidChecksum(_, _), _)) => {},
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn valid_last_words_complete_the_checksum() {
+    let partial = ["crop", "cash", "unable", "insane", "eight", "faith", "inflict", "route", "frame", "loud", "box"];
+
+    let last_words = Mnemonic::valid_last_words(&partial, Language::English).unwrap();
+    assert_eq!(last_words.len(), 128);
+    assert_eq!(&last_words[..5], &["abstract", "add", "ahead", "alter", "amused"]);
+    assert!(last_words.contains(&"vibrant"));
+
+    for word in &last_words {
+        let phrase = format!("{} {}", partial.join(" "), word);
+        Mnemonic::validate(&*phrase, Language::English).unwrap();
+    }
+
+    let partial: Vec<&str> = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword".split(" ").collect();
+    let last_words = Mnemonic::valid_last_words(&partial, Language::English).unwrap();
+    assert_eq!(last_words, vec!["blast", "clever", "fine", "human", "magnet", "reopen", "term", "world"]);
+
+    match Mnemonic::valid_last_words(&partial[..12], Language::English) {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 13),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match Mnemonic::valid_last_words(&["park", "remain", "persen", "kitchen", "mule", "spell", "knee", "armed", "position", "rail", "grid"], Language::English) {
+        Err(Error(ErrorKind::InvalidWord(ref word, 2), _)) => assert_eq!(word, "persen"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn generate_all_valid_last_words_pairs_words_with_entropy() {
+    let partial: Vec<&str> = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword".split(" ").collect();
+
+    let candidates = Mnemonic::generate_all_valid_last_words(&partial, Language::English).unwrap();
+
+    let words: Vec<&str> = candidates.iter().map(|(word, _)| word.as_str()).collect();
+    assert_eq!(words, Mnemonic::valid_last_words(&partial, Language::English).unwrap());
+
+    for (word, entropy) in &candidates {
+        let phrase = format!("{} {}", partial.join(" "), word);
+
+        let mnemonic = Mnemonic::from_string(&*phrase, Language::English, "").unwrap();
+        assert_eq!(mnemonic.as_entropy(), &entropy[..]);
+    }
+
+    // every candidate gives a different wallet
+    for (i, (_, entropy)) in candidates.iter().enumerate() {
+        assert!(candidates[i + 1..].iter().all(|(_, other)| other != entropy));
+    }
+
+    match Mnemonic::generate_all_valid_last_words(&partial[..12], Language::English) {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 13),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+fn validate_reports_invalid_word_and_position() {
+    match Mnemonic::validate("park remain person foo mule spell knee armed position rail grid ankle", Language::English) {
+        Err(Error(ErrorKind::InvalidWord(ref word, index), _)) => {
+            assert_eq!(word, "foo");
+            assert_eq!(index, 3);
+        },
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    // only the first invalid word is reported
+    let result = Mnemonic::from_string("Park remain person kitchen mule spell knee armed positon rail grid ankle", Language::English, "");
+    match result {
+        Err(ref e @ Error(ErrorKind::InvalidWord(_, 0), _)) => assert_eq!(e.to_string(), "Invalid word \"Park\" at position 0"),
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn validate_reports_expected_and_found_checksum() {
+    // swapping the first two words keeps every word valid but breaks the checksum
+    let result = Mnemonic::from_string("remain park person kitchen mule spell knee armed position rail grid ankle", Language::English, "");
+
+    match result {
+        Err(ref e @ Error(ErrorKind::InvalidChecksum(8, 9), _)) => assert_eq!(e.to_string(), "Invalid checksum, expected 0x08 but found 0x09"),
+        other => panic!("unexpected result: {:?}", other.map(|m| m.get_string()))
+    }
+}
+
+#[test]
+fn validate_ignores_extra_whitespace() {
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let expected = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+
+    let inputs = [
+        "  park   remain person kitchen mule spell knee armed position rail grid ankle\n",
+        "park\nremain\nperson\nkitchen\nmule\nspell\nknee\narmed\nposition\nrail\ngrid\nankle",
+        "\tpark remain person kitchen mule spell \r\n knee armed position rail grid ankle ",
+    ];
+
+    for input in &inputs {
+        Mnemonic::validate(*input, Language::English).unwrap();
+
+        let mnemonic = Mnemonic::from_string(*input, Language::English, "TREZOR").unwrap();
+        assert_eq!(mnemonic.as_str(), test_mnemonic);
+        assert_eq!(mnemonic.word_count(), 12);
+        assert_eq!(mnemonic.as_entropy(), expected.as_entropy());
+        assert_eq!(mnemonic.as_seed().as_hex(), expected.as_seed().as_hex());
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn validate_lenient_ignores_case() {
+    let word_list = Language::English.word_list();
+
+    let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let expected = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+
+    let inputs = [
+        "Park remain person kitchen mule spell knee armed position rail grid ankle",
+        "PARK REMAIN PERSON KITCHEN MULE SPELL KNEE ARMED POSITION RAIL GRID ANKLE",
+        "[\"Park\", \"rEmain\", \"PERSON\", \"kitchen\", \"mule\", \"spell\", \"knee\", \"armed\", \"position\", \"rail\", \"grid\", \"Ankle\"]",
+    ];
+
+    for input in &inputs {
+        assert!(Mnemonic::from_string_with_word_list(*input, word_list, "TREZOR").is_err());
+
+        let mnemonic = Mnemonic::from_string_lenient(*input, word_list, "TREZOR").unwrap();
+        assert_eq!(mnemonic.as_str(), test_mnemonic);
+        assert_eq!(mnemonic.as_entropy(), expected.as_entropy());
+        assert_eq!(mnemonic.as_seed().as_hex(), expected.as_seed().as_hex());
+    }
+
+    // lowercasing isn't limited to ASCII
+    let mut path = env::current_dir().unwrap();
+    path.push("src/french.json");
+
+    let french = Mnemonic::get_word_list(path).unwrap();
+    let phrase = Mnemonic::from_entropy_with_word_list(&[0x66; 16], MnemonicType::Type12Words, &french, "").unwrap();
+
+    // typed composed and in capitals, the list has them decomposed and lowercase
+    let typed = "FIDÈLE CLIENT MENER RENVOI FIDÈLE CLIENT MENER RENVOI FIDÈLE CLIENT MENER RENVOI";
+    assert!(Mnemonic::from_string_with_word_list(typed, &french, "").is_err());
+
+    let parsed = Mnemonic::from_string_lenient(typed, &french, "").unwrap();
+    assert_eq!(parsed.as_str(), phrase.as_str());
+    assert_eq!(parsed.as_entropy(), &[0x66; 16][..]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn regenerate_checksum_fixes_the_last_word() {
+    let mut rng = XorShiftRng::from_seed([31, 32, 33, 34]);
+
+    for mnemonic_type in &[MnemonicType::Type12Words, MnemonicType::Type24Words] {
+        let mnemonic = Mnemonic::new_with_rng(*mnemonic_type, Language::English, "TREZOR", &mut rng).unwrap();
+        let word_list = Language::English.word_list();
+
+        // a last word with the same entropy bits but every other checksum
+        let mut indices = mnemonic.to_indices();
+        let last = indices.len() - 1;
+        let mask = (1u16 << mnemonic_type.checksum_bits()) - 1;
+
+        for checksum in 0..=mask {
+            indices[last] = (indices[last] & !mask) | checksum;
+
+            let words: Vec<&str> = indices.iter().map(|n| word_list.words[*n as usize].as_str()).collect();
+            let phrase = words.join(" ");
+
+            let corrected = Mnemonic::regenerate_checksum(&*phrase, Language::English, "TREZOR").unwrap();
+
+            assert_eq!(corrected.as_str(), mnemonic.as_str());
+            assert_eq!(corrected.as_seed().as_bytes(), mnemonic.as_seed().as_bytes());
+            assert!(Mnemonic::validate(corrected.as_str(), Language::English).is_ok());
+        }
+    }
+
+    let wrong_word = "park remain person kitchen mule spell knee armed positoin rail grid ankle";
+    match Mnemonic::regenerate_checksum(wrong_word, Language::English, "") {
+        Err(Error(ErrorKind::InvalidWord(ref word, 8), _)) => assert_eq!(word, "positoin"),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match Mnemonic::regenerate_checksum("park remain person", Language::English, "") {
+        Err(Error(ErrorKind::InvalidWordCount(n), _)) => assert_eq!(n, 3),
+        other => panic!("unexpected result: {:?}", other)
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn validate_phrase_checks_a_large_batch() {
+    let mut rng = XorShiftRng::from_seed([41, 42, 43, 44]);
+    let word_list = Language::English.word_list();
+
+    let phrases: Vec<String> = (0..1000).map(|n| {
+        let mnemonic_type = [MnemonicType::Type12Words, MnemonicType::Type18Words, MnemonicType::Type24Words][n % 3];
+
+        let mut entropy = vec![0u8; mnemonic_type.entropy_bits() / 8];
+        rng.fill_bytes(&mut entropy);
+
+        let indices = entropy_to_indices(&entropy, mnemonic_type).unwrap();
+        let words: Vec<&str> = indices.iter().map(|i| word_list.words[*i as usize].as_str()).collect();
+        words.join(" ")
+    }).collect();
+
+    for phrase in &phrases {
+        validate_phrase(phrase, word_list).unwrap();
+        assert!(Mnemonic::validate(&**phrase, Language::English).is_ok());
+    }
+
+    // swapping the first two words breaks the checksum of most phrases, the rest validate by chance
+    let mut failures = 0;
+    for phrase in &phrases {
+        let mut words: Vec<&str> = phrase.split(' ').collect();
+        if words[0] == words[1] {
+            continue
+        }
+        words.swap(0, 1);
+
+        match validate_phrase(&words.join(" "), word_list) {
+            Ok(()) => {},
+            Err(Error(ErrorKind::InvalidChecksum(..), _)) => failures += 1,
+            Err(e) => panic!("unexpected error: {}", e)
+        }
+    }
+    assert!(failures > 900);
+
+    match validate_phrase("park remain person kitchen mule spell knee armed positoin rail grid ankle", word_list) {
+        Err(Error(ErrorKind::InvalidWord(ref word, 8), _)) => assert_eq!(word, "positoin"),
+        other => panic!("unexpected result: {:?}", other)
+    }
 }
@@ -0,0 +1,274 @@
+extern crate bip39;
+
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::fs::{self, File};
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::process;
+use ::bip39::{Error, ErrorKind, Language, WordList};
+#[cfg(feature = "std")]
+use ::bip39::Mnemonic;
+
+#[test]
+fn bloom_filter_contains_every_english_word() {
+    let word_list = Language::English.word_list();
+
+    assert!(word_list.words.iter().all(|word| word_list.maybe_contains(word)));
+}
+
+#[test]
+fn bloom_filter_contains_every_word_of_each_language() {
+    for language in &[Language::English, Language::Japanese] {
+        let word_list = language.word_list();
+
+        assert!(word_list.words.iter().all(|word| word_list.maybe_contains(word)));
+
+        // copies of a built in list keep the filter that was built with it
+        let copy = word_list.clone();
+        assert!(word_list.words.iter().all(|word| copy.maybe_contains(word)));
+    }
+}
+
+#[test]
+fn bloom_filter_rejects_noise() {
+    let word_list = Language::English.word_list();
+
+    let false_positives = (0..10_000)
+        .map(|i| format!("noise{}", i))
+        .filter(|token| word_list.maybe_contains(token))
+        .count();
+
+    // expected rate is around 0.1%, leave plenty of headroom
+    assert!(false_positives < 100);
+}
+
+#[test]
+fn english_unique_prefixes() {
+    let word_list = Language::English.word_list();
+
+    let prefixes = word_list.unique_prefixes();
+
+    assert_eq!(prefixes.len(), 2048);
+    assert_eq!(prefixes.iter().max(), Some(&4));
+    assert!(prefixes.iter().all(|len| *len > 0));
+
+    // every prefix really is unique, apart from words that are the start of another word
+    for (word, len) in word_list.words.iter().zip(&prefixes) {
+        let prefix: String = word.chars().take(*len).collect();
+        let matches = word_list.words.iter().filter(|w| w.starts_with(&*prefix)).count();
+        assert!(matches == 1 || prefix == *word);
+    }
+}
+
+#[test]
+fn contains_all() {
+    let word_list = Language::English.word_list();
+
+    let phrase: Vec<&str> = "park remain person kitchen mule spell knee armed position rail grid ankle".split(" ").collect();
+    assert!(word_list.contains_all(&phrase));
+    assert!(word_list.contains_all(&[]));
+
+    let mut foreign = vec!["park", "rester"];
+    foreign.extend(phrase.iter());
+    assert!(!word_list.contains_all(&foreign));
+    assert!(!word_list.contains_all(&["Park"]));
+}
+
+#[test]
+fn word_list_as_language() {
+    let mut word_list = Language::English.word_list().clone();
+    assert_eq!(word_list.as_language(), Some(Language::English));
+
+    word_list.language = "klingon".to_string();
+    assert_eq!(word_list.as_language(), None);
+}
+
+#[test]
+fn diff_reports_substituted_word() {
+    let word_list = Language::English.word_list();
+
+    let mut modified = word_list.clone();
+    modified.words[1282] = "parka".to_owned();
+
+    assert_eq!(word_list.diff(&modified), vec![(1282, "park", "parka")]);
+    assert_eq!(modified.diff(word_list), vec![(1282, "parka", "park")]);
+
+    modified.words.pop();
+    assert_eq!(word_list.diff(&modified), vec![(1282, "park", "parka"), (2047, "zoo", "")]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn language_word_list_matches_file() {
+    let mut path = env::current_dir().unwrap();
+    path.push("src/english.json");
+
+    let word_list = Mnemonic::get_word_list(path).unwrap();
+
+    assert_eq!(Language::English.word_list().words, word_list.words);
+    assert!(::std::ptr::eq(Language::English.word_list(), Language::English.word_list()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_reader_matches_get_word_list() {
+    let mut path = env::current_dir().unwrap();
+    path.push("src/english.json");
+
+    let word_list = WordList::from_reader(File::open(&path).unwrap()).unwrap();
+
+    assert_eq!(word_list.words, Mnemonic::get_word_list(path).unwrap().words);
+    assert_eq!(word_list.as_language(), Some(Language::English));
+}
+
+#[test]
+fn from_words_rejects_bad_lists() {
+    let words = Language::English.word_list().words.clone();
+
+    match WordList::from_words("short".to_owned(), words[..2047].to_vec()) {
+        Err(Error(ErrorKind::InvalidWordList(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+
+    let mut duplicated = words.clone();
+    duplicated[2047] = duplicated[0].clone();
+
+    match WordList::from_words("duplicated".to_owned(), duplicated) {
+        Err(Error(ErrorKind::InvalidWordList(ref reason), _)) => assert!(reason.contains("abandon")),
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+
+    let custom = WordList::from_words("custom".to_owned(), words).unwrap();
+    assert_eq!(custom.as_language(), None);
+}
+
+#[test]
+fn validate_structure_rejects_duplicates_and_shared_prefixes() {
+    let mut word_list = Language::English.word_list().clone();
+    assert!(word_list.validate_structure().is_ok());
+
+    word_list.words[1282] = "zoo".to_owned();
+    match word_list.validate_structure() {
+        Err(Error(ErrorKind::InvalidWordList(ref reason), _)) => assert!(reason.contains("\"zoo\" appears more than once")),
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    // "parka" is distinct from every word but shares its first 4 letters with "park"
+    word_list.words[1282] = "park".to_owned();
+    word_list.words[0] = "parka".to_owned();
+    match word_list.validate_structure() {
+        Err(Error(ErrorKind::InvalidWordList(ref reason), _)) => {
+            assert!(reason.contains("\"park\""));
+            assert!(reason.contains("\"parka\""));
+        },
+        other => panic!("unexpected result: {:?}", other)
+    }
+
+    match WordList::from_words("custom".to_owned(), word_list.words.clone()) {
+        Err(Error(ErrorKind::InvalidWordList(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+
+    // every embedded list passes, including those whose words are stored decomposed
+    assert!(Language::Japanese.word_list().validate_structure().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn truncated_word_list_file_reports_parse_error() {
+    let mut path = env::current_dir().unwrap();
+    path.push("src/english.json");
+
+    let json = fs::read(&path).unwrap();
+
+    let mut truncated_path = env::temp_dir();
+    truncated_path.push(format!("bip39-truncated-{}.json", process::id()));
+    File::create(&truncated_path).unwrap().write_all(&json[..json.len() / 2]).unwrap();
+
+    let result = Mnemonic::get_word_list(truncated_path.clone());
+    fs::remove_file(&truncated_path).unwrap();
+
+    match result {
+        Err(Error(ErrorKind::WordListParse(ref e), _)) => assert!(e.is_eof()),
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+
+    let mut missing_path = env::temp_dir();
+    missing_path.push("bip39-missing-word-list.json");
+
+    match Mnemonic::get_word_list(missing_path) {
+        Err(Error(ErrorKind::InvalidFile, _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+}
+
+#[test]
+fn words_with_prefix_in_word_list_order() {
+    let word_list = Language::English.word_list();
+
+    assert_eq!(word_list.words_with_prefix("aba"), vec!["abandon"]);
+    assert_eq!(word_list.words_with_prefix("ab"), vec!["abandon", "ability", "able", "about", "above", "absent",
+                                                        "absorb", "abstract", "absurd", "abuse"]);
+    assert_eq!(word_list.words_with_prefix("abst"), vec!["abstract"]);
+    assert!(word_list.words_with_prefix("xyz").is_empty());
+    assert_eq!(word_list.words_with_prefix("").len(), 2048);
+}
+
+#[test]
+fn words_with_prefix_normalizes_the_prefix() {
+    let word_list = Language::Japanese.word_list();
+
+    // the word list stores "ぎじかがく" decomposed, a composed prefix of 4 characters is unique
+    let gijikagaku = "\u{304d}\u{3099}\u{3057}\u{3099}\u{304b}\u{304b}\u{3099}\u{304f}";
+    assert_eq!(word_list.words_with_prefix("\u{304e}\u{3058}\u{304b}\u{304c}"), vec![gijikagaku]);
+    assert_eq!(word_list.words_with_prefix("\u{304d}\u{3099}\u{3057}\u{3099}\u{304b}\u{304b}\u{3099}"), vec![gijikagaku]);
+    assert_eq!(word_list.words_with_prefix("\u{304e}\u{3058}").len(), 4);
+}
+
+#[test]
+fn nearest_words_suggests_corrections() {
+    let word_list = Language::English.word_list();
+
+    assert_eq!(word_list.nearest_words("abandom", 1), vec!["abandon"]);
+    assert_eq!(word_list.nearest_words("abandon", 0), vec!["abandon"]);
+    assert_eq!(word_list.nearest_words("abandon", 3)[0], "abandon");
+    assert!(word_list.nearest_words("qqqqqqqq", 2).is_empty());
+
+    // the closest words come first, and the list is capped
+    let suggestions = word_list.nearest_words("cat", 2);
+    assert_eq!(suggestions.len(), 10);
+    assert_eq!(suggestions[0], "cat");
+    assert!(suggestions[1..].iter().all(|w| *w != "cat"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_text_reader_matches_json_list() {
+    let mut path = env::current_dir().unwrap();
+    path.push("tests/wordlists/english.txt");
+
+    let word_list = WordList::from_text_reader("english".to_owned(), File::open(&path).unwrap()).unwrap();
+    assert_eq!(word_list.words, Language::English.word_list().words);
+    assert_eq!(word_list.as_language(), Some(Language::English));
+
+    // windows line endings, stray spaces and trailing blank lines are all fine
+    let text = fs::read_to_string(&path).unwrap().replace('\n', " \r\n") + "\r\n\n";
+    let word_list = WordList::from_text_reader("english".to_owned(), text.as_bytes()).unwrap();
+    assert_eq!(word_list.words, Language::English.word_list().words);
+
+    // a blank line in the middle isn't skipped, it counts as an extra (empty) word
+    let lines: Vec<&str> = text.lines().collect();
+    let gapped = format!("{}\n\n{}", lines[..1024].join("\n"), lines[1024..].join("\n"));
+    match WordList::from_text_reader("english".to_owned(), gapped.as_bytes()) {
+        Err(Error(ErrorKind::InvalidWordList(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+
+    match WordList::from_text_reader("english".to_owned(), &[0xff, 0xfe, 0x00][..]) {
+        Err(Error(ErrorKind::InvalidWordList(_), _)) => {},
+        other => panic!("unexpected result: {:?}", other.map(|w| w.language))
+    }
+}
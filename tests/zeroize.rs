@@ -0,0 +1,23 @@
+#![cfg(feature = "zeroize")]
+
+extern crate bip39;
+
+use ::bip39::{Language, Mnemonic};
+
+#[test]
+fn clearing_one_copy_leaves_the_other_intact() {
+    let test_mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_string(test_mnemonic, Language::English, "TREZOR").unwrap();
+    let _ = mnemonic.words_as_slice();
+
+    let copy = mnemonic.clone();
+    let seed = mnemonic.get_seed().clone();
+    drop(mnemonic);
+
+    assert_eq!(copy.as_str(), test_mnemonic);
+    assert_eq!(copy.as_entropy(), &[0u8; 16][..]);
+    assert_eq!(copy.words_as_slice()[11], "about");
+    assert_eq!(seed.get_hex_lower(),
+               "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+    assert_eq!(copy.get_seed().as_bytes(), seed.as_bytes());
+}